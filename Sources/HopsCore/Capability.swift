@@ -31,31 +31,77 @@ public struct ResourceLimits: Codable, Sendable, Equatable {
 
 public struct CapabilityGrant: Codable, Sendable, Equatable {
   public var network: NetworkCapability
+  public var dns: Bool?
   public var filesystem: Set<FilesystemCapability>
   public var allowedPaths: Set<String>
   public var deniedPaths: Set<String>
+  public var pathCapabilities: [String: Set<FilesystemCapability>]
   public var resourceLimits: ResourceLimits
 
   public init(
     network: NetworkCapability = .disabled,
+    dns: Bool? = nil,
     filesystem: Set<FilesystemCapability> = [],
     allowedPaths: Set<String> = [],
     deniedPaths: Set<String> = [],
+    pathCapabilities: [String: Set<FilesystemCapability>] = [:],
     resourceLimits: ResourceLimits = ResourceLimits()
   ) {
     self.network = network
+    self.dns = dns
     self.filesystem = filesystem
     self.allowedPaths = allowedPaths
     self.deniedPaths = deniedPaths
+    self.pathCapabilities = pathCapabilities
     self.resourceLimits = resourceLimits
   }
 
+  private enum CodingKeys: String, CodingKey {
+    case network
+    case dns
+    case filesystem
+    case allowedPaths
+    case deniedPaths
+    case pathCapabilities
+    case resourceLimits
+  }
+
+  public init(from decoder: Decoder) throws {
+    let container = try decoder.container(keyedBy: CodingKeys.self)
+    self.network = try container.decode(NetworkCapability.self, forKey: .network)
+    self.dns = try container.decodeIfPresent(Bool.self, forKey: .dns)
+    self.filesystem = try container.decode(Set<FilesystemCapability>.self, forKey: .filesystem)
+    self.allowedPaths = try container.decode(Set<String>.self, forKey: .allowedPaths)
+    self.deniedPaths = try container.decode(Set<String>.self, forKey: .deniedPaths)
+    self.pathCapabilities =
+      try container.decodeIfPresent(
+        [String: Set<FilesystemCapability>].self, forKey: .pathCapabilities) ?? [:]
+    self.resourceLimits = try container.decode(ResourceLimits.self, forKey: .resourceLimits)
+  }
+
+  public var dnsEnabled: Bool {
+    dns ?? (network == .outbound || network == .full)
+  }
+
+  public func capabilities(for path: String) -> Set<FilesystemCapability> {
+    pathCapabilities[path] ?? filesystem
+  }
+
+  public func enforcedCapabilities(for path: String) -> Set<FilesystemCapability> {
+    var granted = capabilities(for: path)
+    if PolicyValidator.isSensitivePath(path) {
+      granted.remove(.write)
+    }
+    return granted
+  }
+
   public static var `default`: CapabilityGrant {
     CapabilityGrant(
       network: .disabled,
       filesystem: [],
       allowedPaths: [],
       deniedPaths: [],
+      pathCapabilities: [:],
       resourceLimits: ResourceLimits()
     )
   }
@@ -0,0 +1,6 @@
+import Foundation
+
+public enum HopsVersion {
+  public static let current = "0.1.2"
+  public static let protocolVersion: UInt32 = 1
+}
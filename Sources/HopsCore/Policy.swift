@@ -19,6 +19,23 @@ public struct Policy: Codable, Sendable, Equatable {
     }
   }
 
+  public var deniedCapabilities: [String] {
+    let deniedMounts = sandbox.mounts
+      .map(\.destination)
+      .filter { capabilities.deniedPaths.contains($0) }
+    let sharedPaths = capabilities.allowedPaths
+      .filter { path in !sandbox.mounts.contains { $0.destination == path } }
+      .sorted()
+    let deniedSharedPaths = sharedPaths.filter { capabilities.deniedPaths.contains($0) }
+    let deniedWrites = sharedPaths.filter { path in
+      !capabilities.deniedPaths.contains(path)
+        && capabilities.capabilities(for: path).contains(.write)
+        && !capabilities.enforcedCapabilities(for: path).contains(.write)
+    }
+    return (deniedMounts + deniedSharedPaths).map { "mount:\($0)" }
+      + deniedWrites.map { "write:\($0)" }
+  }
+
   public init(
     name: String,
     version: String = "1.0.0",
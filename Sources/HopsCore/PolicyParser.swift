@@ -88,6 +88,16 @@ public struct PolicyParser: Sendable {
         "capabilities.network", "unknown value: \(networkStr)")
     }
 
+    let dns = capTable["dns"]?.bool
+
+    let outboundAllowlist = capTable["outbound_allowlist"]?.array ?? []
+    if !outboundAllowlist.isEmpty && (network == .outbound || network == .full) {
+      throw PolicyParserError.invalidFieldValue(
+        "capabilities.outbound_allowlist",
+        "hopsd does not enforce outbound allowlists, so the sandbox could reach any host; remove the entries or set network to disabled or loopback"
+      )
+    }
+
     var filesystem = Set<FilesystemCapability>()
     if let filesystemArr = capTable["filesystem"]?.array {
       for item in filesystemArr {
@@ -104,7 +114,7 @@ public struct PolicyParser: Sendable {
     var allowedPaths = Set<String>()
     if let allowedArr = capTable["allowed_paths"]?.array {
       for item in allowedArr {
-        if let path = item.string {
+        if let path = item.string ?? item.table?["path"]?.string {
           allowedPaths.insert(path)
         }
       }
@@ -113,19 +123,42 @@ public struct PolicyParser: Sendable {
     var deniedPaths = Set<String>()
     if let deniedArr = capTable["denied_paths"]?.array {
       for item in deniedArr {
-        if let path = item.string {
+        if let path = item.string ?? item.table?["path"]?.string {
           deniedPaths.insert(path)
         }
       }
     }
 
+    var pathCapabilities: [String: Set<FilesystemCapability>] = [:]
+    if let pathTable = capTable["path_capabilities"]?.table {
+      for (path, value) in pathTable {
+        guard let capsArr = value.array else {
+          throw PolicyParserError.invalidFieldValue(
+            "capabilities.path_capabilities", "expected an array for \(path)")
+        }
+        var granted = Set<FilesystemCapability>()
+        for item in capsArr {
+          if let fsStr = item.string {
+            guard let fs = FilesystemCapability(rawValue: fsStr) else {
+              throw PolicyParserError.invalidFieldValue(
+                "capabilities.path_capabilities", "unknown value: \(fsStr)")
+            }
+            granted.insert(fs)
+          }
+        }
+        pathCapabilities[path] = granted
+      }
+    }
+
     let resourceLimits = try parseResourceLimits(from: capTable)
 
     return CapabilityGrant(
       network: network,
+      dns: dns,
       filesystem: filesystem,
       allowedPaths: allowedPaths,
       deniedPaths: deniedPaths,
+      pathCapabilities: pathCapabilities.filter { allowedPaths.contains($0.key) },
       resourceLimits: resourceLimits
     )
   }
@@ -55,7 +55,7 @@ public struct PolicyValidator: Sendable {
   public let minCPUs: UInt
   public let minProcesses: UInt
 
-  private let sensitivePaths = [
+  public static let sensitivePaths = [
     "/etc/shadow",
     "/etc/sudoers",
     "/etc/passwd",
@@ -68,6 +68,17 @@ public struct PolicyValidator: Sendable {
     "/System/Library/Security"
   ]
 
+  public static func isSensitivePath(_ path: String) -> Bool {
+    let expandedPath = NSString(string: path).expandingTildeInPath
+    let canonPath = URL(fileURLWithPath: expandedPath).standardized.path
+    let canonDirectory = canonPath.hasSuffix("/") ? canonPath : canonPath + "/"
+    return sensitivePaths.contains { sensitivePath in
+      canonPath == sensitivePath
+        || canonPath.hasPrefix(sensitivePath + "/")
+        || sensitivePath.hasPrefix(canonDirectory)
+    }
+  }
+
   public init(
     maxMemoryBytes: UInt64 = 8_589_934_592,
     maxCPUs: UInt = 16,
@@ -205,7 +216,7 @@ public struct PolicyValidator: Sendable {
         let resolvedSource = try FileManager.default.destinationOfSymbolicLink(atPath: canonSource)
         let resolvedCanon = canonicalizePath(resolvedSource)
 
-        for sensitivePath in sensitivePaths {
+        for sensitivePath in Self.sensitivePaths {
           let canonSensitive = canonicalizePath(sensitivePath)
           if resolvedCanon.hasPrefix(canonSensitive) || canonSensitive.hasPrefix(resolvedCanon) {
             throw PolicyValidationError.insecureMountConfiguration(
@@ -215,7 +226,7 @@ public struct PolicyValidator: Sendable {
         }
       }
 
-      for sensitivePath in sensitivePaths {
+      for sensitivePath in Self.sensitivePaths {
         let canonSensitive = canonicalizePath(sensitivePath)
         if canonSource.hasPrefix(canonSensitive) || canonSensitive.hasPrefix(canonSource) {
           throw PolicyValidationError.insecureMountConfiguration(
@@ -227,7 +238,7 @@ public struct PolicyValidator: Sendable {
 
     let canonDestination = canonicalizePath(mount.destination)
 
-    for sensitivePath in sensitivePaths {
+    for sensitivePath in Self.sensitivePaths {
       let canonSensitive = canonicalizePath(sensitivePath)
 
       if mount.mode == .readWrite {
@@ -372,6 +372,8 @@ public struct Hops_SandboxStatus: Sendable {
   /// Clears the value of `resourceUsage`. Subsequent reads from it will return its default value.
   public mutating func clearResourceUsage() {self._resourceUsage = nil}
 
+  public var deniedCapabilities: [String] = []
+
   public var unknownFields = SwiftProtobuf.UnknownStorage()
 
   public init() {}
@@ -394,9 +396,34 @@ public struct Hops_SandboxInfo: Sendable {
 
   public var command: [String] = []
 
+  public var deniedCapabilities: [String] = []
+
+  public var startTime: Int64 = 0
+
+  public var endTime: Int64 {
+    get {return _endTime ?? 0}
+    set {_endTime = newValue}
+  }
+  /// Returns true if `endTime` has been explicitly set.
+  public var hasEndTime: Bool {return self._endTime != nil}
+  /// Clears the value of `endTime`. Subsequent reads from it will return its default value.
+  public mutating func clearEndTime() {self._endTime = nil}
+
+  public var exitCode: Int32 {
+    get {return _exitCode ?? 0}
+    set {_exitCode = newValue}
+  }
+  /// Returns true if `exitCode` has been explicitly set.
+  public var hasExitCode: Bool {return self._exitCode != nil}
+  /// Clears the value of `exitCode`. Subsequent reads from it will return its default value.
+  public mutating func clearExitCode() {self._exitCode = nil}
+
   public var unknownFields = SwiftProtobuf.UnknownStorage()
 
   public init() {}
+
+  fileprivate var _endTime: Int64? = nil
+  fileprivate var _exitCode: Int32? = nil
 }
 
 public struct Hops_Policy: Sendable {
@@ -468,11 +495,23 @@ public struct Hops_Capabilities: Sendable {
   /// Clears the value of `filesystem`. Subsequent reads from it will return its default value.
   public mutating func clearFilesystem() {self._filesystem = nil}
 
+  public var outboundAllowlist: [String] = []
+
+  public var dns: Bool {
+    get {return _dns ?? false}
+    set {_dns = newValue}
+  }
+  /// Returns true if `dns` has been explicitly set.
+  public var hasDns: Bool {return self._dns != nil}
+  /// Clears the value of `dns`. Subsequent reads from it will return its default value.
+  public mutating func clearDns() {self._dns = nil}
+
   public var unknownFields = SwiftProtobuf.UnknownStorage()
 
   public init() {}
 
   fileprivate var _filesystem: Hops_FilesystemCapabilities? = nil
+  fileprivate var _dns: Bool? = nil
 }
 
 public struct Hops_FilesystemCapabilities: Sendable {
@@ -609,6 +648,10 @@ public struct Hops_DaemonStatusResponse: Sendable {
 
   public var activeSandboxes: Int32 = 0
 
+  public var version: String = String()
+
+  public var protocolVersion: UInt32 = 0
+
   public var unknownFields = SwiftProtobuf.UnknownStorage()
 
   public init() {}
@@ -913,7 +956,7 @@ extension Hops_StatusRequest: SwiftProtobuf.Message, SwiftProtobuf._MessageImple
 
 extension Hops_SandboxStatus: SwiftProtobuf.Message, SwiftProtobuf._MessageImplementationBase, SwiftProtobuf._ProtoNameProviding {
   public static let protoMessageName: String = _protobuf_package + ".SandboxStatus"
-  public static let _protobuf_nameMap = SwiftProtobuf._NameMap(bytecode: "\0\u{3}sandbox_id\0\u{1}pid\0\u{1}state\0\u{1}command\0\u{3}start_time\0\u{3}end_time\0\u{3}exit_code\0\u{3}resource_usage\0")
+  public static let _protobuf_nameMap = SwiftProtobuf._NameMap(bytecode: "\0\u{3}sandbox_id\0\u{1}pid\0\u{1}state\0\u{1}command\0\u{3}start_time\0\u{3}end_time\0\u{3}exit_code\0\u{3}resource_usage\0\u{3}denied_capabilities\0")
 
   public mutating func decodeMessage<D: SwiftProtobuf.Decoder>(decoder: inout D) throws {
     while let fieldNumber = try decoder.nextFieldNumber() {
@@ -929,6 +972,7 @@ extension Hops_SandboxStatus: SwiftProtobuf.Message, SwiftProtobuf._MessageImple
       case 6: try { try decoder.decodeSingularInt64Field(value: &self._endTime) }()
       case 7: try { try decoder.decodeSingularInt32Field(value: &self._exitCode) }()
       case 8: try { try decoder.decodeSingularMessageField(value: &self._resourceUsage) }()
+      case 9: try { try decoder.decodeRepeatedStringField(value: &self.deniedCapabilities) }()
       default: break
       }
     }
@@ -963,6 +1007,9 @@ extension Hops_SandboxStatus: SwiftProtobuf.Message, SwiftProtobuf._MessageImple
     try { if let v = self._resourceUsage {
       try visitor.visitSingularMessageField(value: v, fieldNumber: 8)
     } }()
+    if !self.deniedCapabilities.isEmpty {
+      try visitor.visitRepeatedStringField(value: self.deniedCapabilities, fieldNumber: 9)
+    }
     try unknownFields.traverse(visitor: &visitor)
   }
 
@@ -975,6 +1022,7 @@ extension Hops_SandboxStatus: SwiftProtobuf.Message, SwiftProtobuf._MessageImple
     if lhs._endTime != rhs._endTime {return false}
     if lhs._exitCode != rhs._exitCode {return false}
     if lhs._resourceUsage != rhs._resourceUsage {return false}
+    if lhs.deniedCapabilities != rhs.deniedCapabilities {return false}
     if lhs.unknownFields != rhs.unknownFields {return false}
     return true
   }
@@ -982,7 +1030,7 @@ extension Hops_SandboxStatus: SwiftProtobuf.Message, SwiftProtobuf._MessageImple
 
 extension Hops_SandboxInfo: SwiftProtobuf.Message, SwiftProtobuf._MessageImplementationBase, SwiftProtobuf._ProtoNameProviding {
   public static let protoMessageName: String = _protobuf_package + ".SandboxInfo"
-  public static let _protobuf_nameMap = SwiftProtobuf._NameMap(bytecode: "\0\u{3}sandbox_id\0\u{1}pid\0\u{1}state\0\u{1}command\0")
+  public static let _protobuf_nameMap = SwiftProtobuf._NameMap(bytecode: "\0\u{3}sandbox_id\0\u{1}pid\0\u{1}state\0\u{1}command\0\u{3}denied_capabilities\0\u{3}start_time\0\u{3}end_time\0\u{3}exit_code\0")
 
   public mutating func decodeMessage<D: SwiftProtobuf.Decoder>(decoder: inout D) throws {
     while let fieldNumber = try decoder.nextFieldNumber() {
@@ -994,12 +1042,20 @@ extension Hops_SandboxInfo: SwiftProtobuf.Message, SwiftProtobuf._MessageImpleme
       case 2: try { try decoder.decodeSingularInt32Field(value: &self.pid) }()
       case 3: try { try decoder.decodeSingularEnumField(value: &self.state) }()
       case 4: try { try decoder.decodeRepeatedStringField(value: &self.command) }()
+      case 5: try { try decoder.decodeRepeatedStringField(value: &self.deniedCapabilities) }()
+      case 6: try { try decoder.decodeSingularInt64Field(value: &self.startTime) }()
+      case 7: try { try decoder.decodeSingularInt64Field(value: &self._endTime) }()
+      case 8: try { try decoder.decodeSingularInt32Field(value: &self._exitCode) }()
       default: break
       }
     }
   }
 
   public func traverse<V: SwiftProtobuf.Visitor>(visitor: inout V) throws {
+    // The use of inline closures is to circumvent an issue where the compiler
+    // allocates stack space for every if/case branch local when no optimizations
+    // are enabled. https://github.com/apple/swift-protobuf/issues/1034 and
+    // https://github.com/apple/swift-protobuf/issues/1182
     if !self.sandboxID.isEmpty {
       try visitor.visitSingularStringField(value: self.sandboxID, fieldNumber: 1)
     }
@@ -1012,6 +1068,18 @@ extension Hops_SandboxInfo: SwiftProtobuf.Message, SwiftProtobuf._MessageImpleme
     if !self.command.isEmpty {
       try visitor.visitRepeatedStringField(value: self.command, fieldNumber: 4)
     }
+    if !self.deniedCapabilities.isEmpty {
+      try visitor.visitRepeatedStringField(value: self.deniedCapabilities, fieldNumber: 5)
+    }
+    if self.startTime != 0 {
+      try visitor.visitSingularInt64Field(value: self.startTime, fieldNumber: 6)
+    }
+    try { if let v = self._endTime {
+      try visitor.visitSingularInt64Field(value: v, fieldNumber: 7)
+    } }()
+    try { if let v = self._exitCode {
+      try visitor.visitSingularInt32Field(value: v, fieldNumber: 8)
+    } }()
     try unknownFields.traverse(visitor: &visitor)
   }
 
@@ -1020,6 +1088,10 @@ extension Hops_SandboxInfo: SwiftProtobuf.Message, SwiftProtobuf._MessageImpleme
     if lhs.pid != rhs.pid {return false}
     if lhs.state != rhs.state {return false}
     if lhs.command != rhs.command {return false}
+    if lhs.deniedCapabilities != rhs.deniedCapabilities {return false}
+    if lhs.startTime != rhs.startTime {return false}
+    if lhs._endTime != rhs._endTime {return false}
+    if lhs._exitCode != rhs._exitCode {return false}
     if lhs.unknownFields != rhs.unknownFields {return false}
     return true
   }
@@ -1101,7 +1173,7 @@ extension Hops_SandboxConfig: SwiftProtobuf.Message, SwiftProtobuf._MessageImple
 
 extension Hops_Capabilities: SwiftProtobuf.Message, SwiftProtobuf._MessageImplementationBase, SwiftProtobuf._ProtoNameProviding {
   public static let protoMessageName: String = _protobuf_package + ".Capabilities"
-  public static let _protobuf_nameMap = SwiftProtobuf._NameMap(bytecode: "\0\u{1}network\0\u{1}filesystem\0")
+  public static let _protobuf_nameMap = SwiftProtobuf._NameMap(bytecode: "\0\u{1}network\0\u{1}filesystem\0\u{3}outbound_allowlist\0\u{1}dns\0")
 
   public mutating func decodeMessage<D: SwiftProtobuf.Decoder>(decoder: inout D) throws {
     while let fieldNumber = try decoder.nextFieldNumber() {
@@ -1111,6 +1183,8 @@ extension Hops_Capabilities: SwiftProtobuf.Message, SwiftProtobuf._MessageImplem
       switch fieldNumber {
       case 1: try { try decoder.decodeSingularEnumField(value: &self.network) }()
       case 2: try { try decoder.decodeSingularMessageField(value: &self._filesystem) }()
+      case 3: try { try decoder.decodeRepeatedStringField(value: &self.outboundAllowlist) }()
+      case 4: try { try decoder.decodeSingularBoolField(value: &self._dns) }()
       default: break
       }
     }
@@ -1127,12 +1201,20 @@ extension Hops_Capabilities: SwiftProtobuf.Message, SwiftProtobuf._MessageImplem
     try { if let v = self._filesystem {
       try visitor.visitSingularMessageField(value: v, fieldNumber: 2)
     } }()
+    if !self.outboundAllowlist.isEmpty {
+      try visitor.visitRepeatedStringField(value: self.outboundAllowlist, fieldNumber: 3)
+    }
+    try { if let v = self._dns {
+      try visitor.visitSingularBoolField(value: v, fieldNumber: 4)
+    } }()
     try unknownFields.traverse(visitor: &visitor)
   }
 
   public static func ==(lhs: Hops_Capabilities, rhs: Hops_Capabilities) -> Bool {
     if lhs.network != rhs.network {return false}
     if lhs._filesystem != rhs._filesystem {return false}
+    if lhs.outboundAllowlist != rhs.outboundAllowlist {return false}
+    if lhs._dns != rhs._dns {return false}
     if lhs.unknownFields != rhs.unknownFields {return false}
     return true
   }
@@ -1424,7 +1506,7 @@ extension Hops_DaemonStatusRequest: SwiftProtobuf.Message, SwiftProtobuf._Messag
 
 extension Hops_DaemonStatusResponse: SwiftProtobuf.Message, SwiftProtobuf._MessageImplementationBase, SwiftProtobuf._ProtoNameProviding {
   public static let protoMessageName: String = _protobuf_package + ".DaemonStatusResponse"
-  public static let _protobuf_nameMap = SwiftProtobuf._NameMap(bytecode: "\0\u{1}pid\0\u{3}start_time\0\u{3}active_sandboxes\0")
+  public static let _protobuf_nameMap = SwiftProtobuf._NameMap(bytecode: "\0\u{1}pid\0\u{3}start_time\0\u{3}active_sandboxes\0\u{1}version\0\u{3}protocol_version\0")
 
   public mutating func decodeMessage<D: SwiftProtobuf.Decoder>(decoder: inout D) throws {
     while let fieldNumber = try decoder.nextFieldNumber() {
@@ -1435,6 +1517,8 @@ extension Hops_DaemonStatusResponse: SwiftProtobuf.Message, SwiftProtobuf._Messa
       case 1: try { try decoder.decodeSingularInt32Field(value: &self.pid) }()
       case 2: try { try decoder.decodeSingularInt64Field(value: &self.startTime) }()
       case 3: try { try decoder.decodeSingularInt32Field(value: &self.activeSandboxes) }()
+      case 4: try { try decoder.decodeSingularStringField(value: &self.version) }()
+      case 5: try { try decoder.decodeSingularUInt32Field(value: &self.protocolVersion) }()
       default: break
       }
     }
@@ -1450,6 +1534,12 @@ extension Hops_DaemonStatusResponse: SwiftProtobuf.Message, SwiftProtobuf._Messa
     if self.activeSandboxes != 0 {
       try visitor.visitSingularInt32Field(value: self.activeSandboxes, fieldNumber: 3)
     }
+    if !self.version.isEmpty {
+      try visitor.visitSingularStringField(value: self.version, fieldNumber: 4)
+    }
+    if self.protocolVersion != 0 {
+      try visitor.visitSingularUInt32Field(value: self.protocolVersion, fieldNumber: 5)
+    }
     try unknownFields.traverse(visitor: &visitor)
   }
 
@@ -1457,6 +1547,8 @@ extension Hops_DaemonStatusResponse: SwiftProtobuf.Message, SwiftProtobuf._Messa
     if lhs.pid != rhs.pid {return false}
     if lhs.startTime != rhs.startTime {return false}
     if lhs.activeSandboxes != rhs.activeSandboxes {return false}
+    if lhs.version != rhs.version {return false}
+    if lhs.protocolVersion != rhs.protocolVersion {return false}
     if lhs.unknownFields != rhs.unknownFields {return false}
     return true
   }
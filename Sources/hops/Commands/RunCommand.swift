@@ -612,11 +612,15 @@ struct DaemonClient {
 
     var capabilities = Hops_Capabilities()
     capabilities.network = convertNetworkCapability(policy.capabilities.network)
+    if let dns = policy.capabilities.dns {
+      capabilities.dns = dns
+    }
 
     var filesystem = Hops_FilesystemCapabilities()
-    filesystem.read = Array(policy.capabilities.allowedPaths)
-    filesystem.write = Array(policy.capabilities.allowedPaths)
-    filesystem.execute = Array(policy.capabilities.allowedPaths)
+    let allowedPaths = policy.capabilities.allowedPaths.sorted()
+    filesystem.read = allowedPaths.filter { policy.capabilities.capabilities(for: $0).contains(.read) }
+    filesystem.write = allowedPaths.filter { policy.capabilities.capabilities(for: $0).contains(.write) }
+    filesystem.execute = allowedPaths.filter { policy.capabilities.capabilities(for: $0).contains(.execute) }
     capabilities.filesystem = filesystem
 
     protoPolicy.capabilities = capabilities
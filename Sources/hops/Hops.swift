@@ -1,5 +1,6 @@
 import ArgumentParser
 import Foundation
+import HopsCore
 
 @main
 struct Hops: AsyncParsableCommand {
@@ -19,7 +20,7 @@ struct Hops: AsyncParsableCommand {
         hops profile list
         hops system start
       """,
-    version: "0.1.0",
+    version: HopsVersion.current,
     subcommands: [
       InitCommand.self,
       RunCommand.self,
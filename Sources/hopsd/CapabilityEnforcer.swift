@@ -32,7 +32,7 @@ enum CapabilityEnforcer {
     config.hostname = sandbox.hostname ?? policy.name
     
     let processedCommand = processCommand(command: command, allocateTty: allocateTty)
-    let needsDNS = capabilities.network == .outbound || capabilities.network == .full
+    let needsDNS = capabilities.dnsEnabled
     
     if needsDNS && !processedCommand.isEmpty {
       let dnsSetup = "echo 'nameserver 8.8.8.8' > /etc/resolv.conf && echo 'nameserver 8.8.4.4' >> /etc/resolv.conf"
@@ -185,8 +185,11 @@ enum CapabilityEnforcer {
       }
     }
 
-    for path in capabilities.allowedPaths where !sandbox.mounts.contains(where: { $0.destination == path }) {
-      let isWritable = capabilities.filesystem.contains(.write)
+    for path in capabilities.allowedPaths
+    where !sandbox.mounts.contains(where: { $0.destination == path })
+      && !capabilities.deniedPaths.contains(path)
+    {
+      let isWritable = capabilities.enforcedCapabilities(for: path).contains(.write)
       let options = isWritable ? [] : ["ro"]
       let mount = Mount.share(
         source: path,
@@ -97,6 +97,8 @@ actor ContainerService: Hops_HopsServiceAsyncProvider {
         rootfs = defaultRootfs
       }
 
+      policy.sandbox.environment.merge(request.environment) { _, requested in requested }
+
       guard FileManager.default.fileExists(atPath: rootfs.path) else {
         throw ContainerServiceError.rootfsNotFound(rootfs.path)
       }
@@ -172,6 +174,8 @@ actor ContainerService: Hops_HopsServiceAsyncProvider {
       rootfs = defaultRootfs
     }
 
+    policy.sandbox.environment.merge(request.environment) { _, requested in requested }
+
     guard FileManager.default.fileExists(atPath: rootfs.path) else {
       throw ContainerServiceError.rootfsNotFound(rootfs.path)
     }
@@ -268,6 +272,14 @@ actor ContainerService: Hops_HopsServiceAsyncProvider {
       protoInfo.pid = info.pid
       protoInfo.state = info.state == "running" ? .running : .stopped
       protoInfo.command = info.command
+      protoInfo.deniedCapabilities = info.deniedCapabilities
+      protoInfo.startTime = Int64(info.startedAt?.timeIntervalSince1970 ?? 0)
+      if let finishedAt = info.finishedAt {
+        protoInfo.endTime = Int64(finishedAt.timeIntervalSince1970)
+      }
+      if let exitCode = info.exitCode {
+        protoInfo.exitCode = Int32(exitCode)
+      }
       return protoInfo
     }
 
@@ -295,6 +307,13 @@ actor ContainerService: Hops_HopsServiceAsyncProvider {
       protoStatus.state = status.state == "running" ? .running : .stopped
       protoStatus.command = info?.command ?? []
       protoStatus.startTime = Int64(status.startedAt?.timeIntervalSince1970 ?? 0)
+      protoStatus.deniedCapabilities = status.deniedCapabilities
+      if let finishedAt = status.finishedAt {
+        protoStatus.endTime = Int64(finishedAt.timeIntervalSince1970)
+      }
+      if let exitCode = status.exitCode {
+        protoStatus.exitCode = Int32(exitCode)
+      }
 
       if let stats = try? await manager.getStatistics(id: request.sandboxID) {
         var resourceUsage = Hops_ResourceUsage()
@@ -319,10 +338,22 @@ actor ContainerService: Hops_HopsServiceAsyncProvider {
     if protoPolicy.hasCapabilities {
       let protoCaps = protoPolicy.capabilities
       capabilities.network = convertNetworkAccess(protoCaps.network)
+      capabilities.dns = protoCaps.hasDns ? protoCaps.dns : nil
+
+      if !protoCaps.outboundAllowlist.isEmpty {
+        throw ContainerServiceError.outboundAllowlistUnsupported(protoCaps.outboundAllowlist)
+      }
 
       if protoCaps.hasFilesystem {
         let fs = protoCaps.filesystem
         capabilities.allowedPaths = Set(fs.read + fs.write + fs.execute)
+        for path in capabilities.allowedPaths {
+          var granted = Set<FilesystemCapability>()
+          if fs.read.contains(path) { granted.insert(.read) }
+          if fs.write.contains(path) { granted.insert(.write) }
+          if fs.execute.contains(path) { granted.insert(.execute) }
+          capabilities.pathCapabilities[path] = granted
+        }
       }
     }
 
@@ -418,6 +449,8 @@ actor ContainerService: Hops_HopsServiceAsyncProvider {
       response.pid = ProcessInfo.processInfo.processIdentifier
       response.startTime = Int64(Date().timeIntervalSince1970)
       response.activeSandboxes = 0
+      response.version = HopsVersion.current
+      response.protocolVersion = HopsVersion.protocolVersion
       return response
     }
 
@@ -427,6 +460,8 @@ actor ContainerService: Hops_HopsServiceAsyncProvider {
     response.pid = status.pid
     response.startTime = Int64(status.startTime.timeIntervalSince1970)
     response.activeSandboxes = Int32(status.activeSandboxes)
+    response.version = HopsVersion.current
+    response.protocolVersion = HopsVersion.protocolVersion
 
     return response
   }
@@ -436,6 +471,7 @@ enum ContainerServiceError: Error {
   case managerNotAvailable
   case rootfsNotFound(String)
   case invalidMemoryFormat(String)
+  case outboundAllowlistUnsupported([String])
 }
 
 extension ContainerServiceError: LocalizedError {
@@ -465,6 +501,16 @@ extension ContainerServiceError: LocalizedError {
         
         Examples: 512M, 1G, 2048MB
         """
+    case .outboundAllowlistUnsupported(let entries):
+      return """
+        Outbound allowlist is not supported: \(entries.joined(separator: ", "))
+
+        hopsd cannot restrict outbound connections to specific hosts, so running
+        this policy would give the sandbox unrestricted outbound access.
+
+        Remove the outbound_allowlist entries from the profile, or set network
+        access to disabled or loopback.
+        """
     }
   }
 }
@@ -476,9 +522,13 @@ public struct SandboxInfo: Codable, Sendable {
   public let pid: Int32
   public let state: String
   public let startedAt: Date?
+  public let finishedAt: Date?
+  public let exitCode: Int?
+  public let deniedCapabilities: [String]
 
   public init(
-    id: String, policyName: String, command: [String], pid: Int32, state: String, startedAt: Date?
+    id: String, policyName: String, command: [String], pid: Int32, state: String, startedAt: Date?,
+    finishedAt: Date? = nil, exitCode: Int? = nil, deniedCapabilities: [String] = []
   ) {
     self.id = id
     self.policyName = policyName
@@ -486,6 +536,9 @@ public struct SandboxInfo: Codable, Sendable {
     self.pid = pid
     self.state = state
     self.startedAt = startedAt
+    self.finishedAt = finishedAt
+    self.exitCode = exitCode
+    self.deniedCapabilities = deniedCapabilities
   }
 }
 
@@ -496,6 +549,7 @@ public struct SandboxStatus: Codable, Sendable {
   public let exitCode: Int?
   public let startedAt: Date?
   public let finishedAt: Date?
+  public let deniedCapabilities: [String]
 
   public init(
     id: String,
@@ -503,7 +557,8 @@ public struct SandboxStatus: Codable, Sendable {
     state: String,
     exitCode: Int?,
     startedAt: Date?,
-    finishedAt: Date?
+    finishedAt: Date?,
+    deniedCapabilities: [String] = []
   ) {
     self.id = id
     self.pid = pid
@@ -511,5 +566,6 @@ public struct SandboxStatus: Codable, Sendable {
     self.exitCode = exitCode
     self.startedAt = startedAt
     self.finishedAt = finishedAt
+    self.deniedCapabilities = deniedCapabilities
   }
 }
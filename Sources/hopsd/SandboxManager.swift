@@ -136,7 +136,8 @@ actor SandboxManager {
       command: command,
       pid: generateContainerPid(id),
       startedAt: Date(),
-      keep: keep
+      keep: keep,
+      deniedCapabilities: policy.deniedCapabilities
     )
 
     await daemon?.incrementActiveSandboxCount()
@@ -169,7 +170,8 @@ actor SandboxManager {
       state: "running",
       exitCode: nil,
       startedAt: containerInfo[id]?.startedAt,
-      finishedAt: nil
+      finishedAt: nil,
+      deniedCapabilities: containerInfo[id]?.deniedCapabilities ?? []
     )
   }
 
@@ -256,7 +258,8 @@ actor SandboxManager {
             command: command,
             pid: generateContainerPid(id),
             startedAt: Date(),
-            keep: keep
+            keep: keep,
+            deniedCapabilities: policy.deniedCapabilities
           )
 
           await self.daemon?.incrementActiveSandboxCount()
@@ -319,7 +322,10 @@ actor SandboxManager {
         command: metadata.command,
         pid: metadata.pid,
         state: containers[id] != nil ? "running" : "stopped",
-        startedAt: metadata.startedAt
+        startedAt: metadata.startedAt,
+        finishedAt: metadata.finishedAt,
+        exitCode: metadata.exitCode,
+        deniedCapabilities: metadata.deniedCapabilities
       )
     }
   }
@@ -348,7 +354,8 @@ actor SandboxManager {
       state: isRunning ? "running" : "stopped",
       exitCode: metadata.exitCode,
       startedAt: metadata.startedAt,
-      finishedAt: metadata.finishedAt
+      finishedAt: metadata.finishedAt,
+      deniedCapabilities: metadata.deniedCapabilities
     )
   }
 
@@ -529,6 +536,7 @@ private struct ContainerMetadata {
   let pid: Int32
   let startedAt: Date
   let keep: Bool
+  let deniedCapabilities: [String]
   var finishedAt: Date?
   var exitCode: Int?
 }
@@ -0,0 +1,18 @@
+import Foundation
+import XCTest
+
+@testable import HopsCore
+
+final class HopsVersionTests: XCTestCase {
+  func testCurrentVersionMatchesUncommitJSON() throws {
+    let manifest = URL(fileURLWithPath: #filePath)
+      .deletingLastPathComponent()
+      .deletingLastPathComponent()
+      .deletingLastPathComponent()
+      .appendingPathComponent("uncommit.json")
+    let data = try Data(contentsOf: manifest)
+    let json = try XCTUnwrap(JSONSerialization.jsonObject(with: data) as? [String: Any])
+
+    XCTAssertEqual(HopsVersion.current, json["version"] as? String)
+  }
+}
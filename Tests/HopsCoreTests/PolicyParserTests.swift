@@ -204,6 +204,101 @@ final class PolicyParserTests: XCTestCase {
     XCTAssertTrue(policy.capabilities.deniedPaths.isEmpty)
   }
 
+  func testParsePathCapabilitiesOverrideGlobalFilesystem() throws {
+    let toml = """
+      name = "test"
+
+      [capabilities]
+      filesystem = ["read", "write", "execute"]
+      allowed_paths = ["/usr", "/tmp"]
+
+      [capabilities.path_capabilities]
+      "/usr" = ["read", "execute"]
+      """
+
+    let policy = try parser.parse(fromString: toml)
+    XCTAssertEqual(policy.capabilities.capabilities(for: "/usr"), [.read, .execute])
+    XCTAssertEqual(policy.capabilities.capabilities(for: "/tmp"), [.read, .write, .execute])
+  }
+
+  func testParsePathCapabilitiesInvalidValueThrowsError() {
+    let toml = """
+      name = "test"
+
+      [capabilities]
+      allowed_paths = ["/usr"]
+
+      [capabilities.path_capabilities]
+      "/usr" = ["admin"]
+      """
+
+    XCTAssertThrowsError(try parser.parse(fromString: toml)) { error in
+      if case PolicyParserError.invalidFieldValue(let field, _) = error {
+        XCTAssertEqual(field, "capabilities.path_capabilities")
+      } else {
+        XCTFail("Expected invalidFieldValue error")
+      }
+    }
+  }
+
+  func testParseOutboundAllowlistWithOutboundNetworkThrowsError() {
+    let toml = """
+      name = "test"
+
+      [capabilities]
+      network = "outbound"
+      outbound_allowlist = ["api.github.com:443"]
+      """
+
+    XCTAssertThrowsError(try parser.parse(fromString: toml)) { error in
+      if case PolicyParserError.invalidFieldValue(let field, _) = error {
+        XCTAssertEqual(field, "capabilities.outbound_allowlist")
+      } else {
+        XCTFail("Expected invalidFieldValue error")
+      }
+    }
+  }
+
+  func testParseOutboundAllowlistIgnoredWithoutOutboundNetwork() throws {
+    let toml = """
+      name = "test"
+
+      [capabilities]
+      network = "disabled"
+      outbound_allowlist = ["api.github.com:443"]
+      """
+
+    let policy = try parser.parse(fromString: toml)
+    XCTAssertEqual(policy.capabilities.network, .disabled)
+  }
+
+  func testParseDNSOverridesNetworkDefault() throws {
+    let toml = """
+      name = "test"
+
+      [capabilities]
+      network = "outbound"
+      dns = false
+      """
+
+    let policy = try parser.parse(fromString: toml)
+    XCTAssertEqual(policy.capabilities.dns, false)
+    XCTAssertFalse(policy.capabilities.dnsEnabled)
+  }
+
+  func testParseDNSDefaultsToNetworkLevel() throws {
+    let toml = """
+      name = "test"
+
+      [capabilities]
+      network = "full"
+      """
+
+    let policy = try parser.parse(fromString: toml)
+    XCTAssertNil(policy.capabilities.dns)
+    XCTAssertTrue(policy.capabilities.dnsEnabled)
+  }
+
   func testParseMalformedTOMLThrowsError() {
     let toml = """
       name = "test
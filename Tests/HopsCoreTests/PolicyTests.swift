@@ -7,4 +7,59 @@ final class PolicyTests: XCTestCase {
     let policy = Policy.default
     XCTAssertEqual(policy.name, "default")
   }
+
+  func testCapabilityGrantDecodesWithoutPathCapabilities() throws {
+    let json = """
+      {
+        "network": "outbound",
+        "filesystem": ["read"],
+        "allowedPaths": ["/data"],
+        "deniedPaths": [],
+        "resourceLimits": {}
+      }
+      """
+
+    let grant = try JSONDecoder().decode(CapabilityGrant.self, from: Data(json.utf8))
+
+    XCTAssertEqual(grant.network, .outbound)
+    XCTAssertTrue(grant.pathCapabilities.isEmpty)
+    XCTAssertEqual(grant.capabilities(for: "/data"), [.read])
+  }
+
+  func testDeniedCapabilitiesReportsWriteToSensitivePath() {
+    let policy = Policy(
+      name: "test",
+      capabilities: CapabilityGrant(
+        filesystem: [.read, .write],
+        allowedPaths: ["/etc", "/data"]
+      )
+    )
+
+    XCTAssertEqual(policy.deniedCapabilities, ["write:/etc"])
+    XCTAssertEqual(policy.capabilities.enforcedCapabilities(for: "/etc"), [.read])
+    XCTAssertEqual(policy.capabilities.enforcedCapabilities(for: "/data"), [.read, .write])
+  }
+
+  func testDeniedCapabilitiesReportsPathsOverriddenByDeniedPaths() {
+    let policy = Policy(
+      name: "test",
+      capabilities: CapabilityGrant(
+        filesystem: [.read],
+        allowedPaths: ["/data"],
+        deniedPaths: ["/data", "/tmp"]
+      ),
+      sandbox: SandboxConfig(mounts: [.tmpfs(destination: "/tmp")])
+    )
+
+    XCTAssertEqual(policy.deniedCapabilities, ["mount:/tmp", "mount:/data"])
+  }
+
+  func testDeniedCapabilitiesEmptyForOrdinaryPolicy() {
+    let policy = Policy(
+      name: "test",
+      capabilities: CapabilityGrant(filesystem: [.read, .write], allowedPaths: ["/data"])
+    )
+
+    XCTAssertTrue(policy.deniedCapabilities.isEmpty)
+  }
 }
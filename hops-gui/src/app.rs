@@ -1,33 +1,190 @@
-use crate::grpc_client::{GrpcClient, GrpcError};
-use crate::models::capability::{FilesystemCapability, NetworkCapability};
-use crate::models::policy::Policy;
+use crate::grpc_client::hops::{ResourceUsage, SandboxState, SandboxStatus};
+use crate::grpc_client::{policy_preview_json, policy_proto_debug, GrpcClient, GrpcError};
+use crate::models::capability::{self, FilesystemCapability, NetworkCapability, PathEntry};
+use crate::models::policy::{Policy, PolicyIssue};
+use crate::models::templates::ProfileTemplate;
+use crate::theme::{self, AppTheme};
 use crate::utils::config;
-use crate::views::{profile_editor, profile_list, run_history};
+use crate::utils::history;
+use crate::utils::settings::{self, ConnectionMode, SandboxLaunch, Settings, WindowGeometry};
+use crate::views::{
+    modal, notification, profile_compare, profile_editor, profile_list, run_history,
+    settings as settings_view,
+};
 use iced::{
     widget::{container, row},
     Element, Length, Task, Theme,
 };
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+const UNDO_DELETE_TIMEOUT: Duration = Duration::from_secs(8);
+const HISTORY_FILTER_DEBOUNCE: Duration = Duration::from_millis(150);
+const WINDOW_GEOMETRY_SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+const MIN_CPU_SLIDER_MAX: u32 = 4;
+const STATS_HISTORY_LEN: usize = 30;
+const SAVED_TOAST_DURATION: Duration = Duration::from_secs(3);
+const COPIED_TOAST_DURATION: Duration = Duration::from_secs(2);
+const STOPPED_TOAST_DURATION: Duration = Duration::from_secs(3);
+const VALIDATION_FLASH_DURATION: Duration = Duration::from_millis(1200);
+const SECONDS_PER_DAY: i64 = 86_400;
 
 pub struct HopsGui {
     pub profiles: Vec<Policy>,
     pub selected_profile: Option<usize>,
+    pub dirty: bool,
+    pub editor_snapshot: Option<Policy>,
+    pub saving: Option<Policy>,
+    pub pending_navigation: Option<PendingNavigation>,
+    pub navigation_after_save: Option<PendingNavigation>,
+    pub external_change: Option<Policy>,
+    pub pending_delete: Option<usize>,
+    pub show_template_gallery: bool,
+    pub bulk_edit: Option<BulkEdit>,
+    pub collapsed: HashSet<String>,
+    pub last_deleted: Option<DeletedProfile>,
+    pub undo_generation: u64,
     pub view_mode: ViewMode,
     pub path_inputs: PathInputs,
     pub validation_errors: ValidationErrors,
+    pub validation_flash: bool,
+    pub notification_generation: u64,
+    pub validation_flash_generation: u64,
     pub run_history: Vec<RunRecord>,
-    pub history_filter: String,
-    pub grpc_client: Option<GrpcClient>,
+    pub history_filter: HistoryFilter,
+    pub history_filter_generation: u64,
+    pub window_geometry_generation: u64,
+    pub history_sort: HistorySortOrder,
+    pub history_page: usize,
+    pub sandbox_details: Option<SandboxDetails>,
+    pub sandbox_stats: HashMap<String, Vec<ResourceUsage>>,
+    pub policy_preview: Option<String>,
+    pub pending_clear_history: bool,
+    pub pending_stop: Option<PendingStop>,
+    pub pending_stop_all: bool,
+    pub stop_all: Option<StopAllProgress>,
+    pub profile_filter: String,
+    pub profile_network_filter: NetworkFilter,
+    pub highlighted_profile: Option<usize>,
+    pub compare_left: Option<String>,
+    pub compare_right: Option<String>,
+    pub settings: Settings,
+    pub grpc_client: Option<DaemonClient>,
+    pub connection_generation: u64,
     pub daemon_status: DaemonStatus,
+    pub connection_error: Option<String>,
+    pub version_mismatch: Option<String>,
+    pub connection_inputs: ConnectionInputs,
     pub loading_state: LoadingState,
+    pub notification: Option<Notification>,
     pub memory_unit: MemoryUnit,
     pub memory_display_value: String,
+    pub host_cores: u32,
+    pub run_command_input: String,
+    pub run_command_error: Option<String>,
+    pub policy_issues: Vec<PolicyIssue>,
+    pub validated_profile: Option<(Policy, ConnectionMode)>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct PathInputs {
     pub allowed_input: String,
     pub denied_input: String,
+    pub outbound_input: String,
+    pub allowed_filter: String,
+    pub denied_filter: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeletedProfile {
+    pub name: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct SandboxDetails {
+    pub sandbox_id: String,
+    pub status: Option<Result<SandboxStatus, String>>,
+}
+
+#[derive(Debug)]
+pub struct DaemonClient {
+    generation: u64,
+    client: GrpcClient,
+}
+
+impl std::ops::Deref for DaemonClient {
+    type Target = GrpcClient;
+
+    fn deref(&self) -> &GrpcClient {
+        &self.client
+    }
+}
+
+impl std::ops::DerefMut for DaemonClient {
+    fn deref_mut(&mut self) -> &mut GrpcClient {
+        &mut self.client
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingStop {
+    pub sandbox_id: String,
+    pub force: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StopAllProgress {
+    pub queue: Vec<String>,
+    pub total: usize,
+    pub stopped: usize,
+    pub failed: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    pub input: String,
+    pub query: String,
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionInputs {
+    pub mode: ConnectionMode,
+    pub socket_path: String,
+    pub tcp_address: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub message: String,
+    pub is_error: bool,
+}
+
+impl Notification {
+    pub fn success(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            is_error: false,
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            is_error: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SaveState {
+    Clean,
+    Dirty,
+    Saving,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -39,17 +196,49 @@ pub struct ValidationErrors {
 pub struct RunRecord {
     pub id: String,
     pub profile_name: String,
+    pub command: String,
     pub start_time: String,
+    pub started_at: i64,
     pub duration: String,
     pub exit_code: i32,
+    pub pid: i32,
+    pub state: SandboxState,
     pub denied_capabilities: Vec<String>,
 }
 
+impl RunRecord {
+    pub fn is_running(&self) -> bool {
+        matches!(self.state, SandboxState::Starting | SandboxState::Running)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ViewMode {
     ProfileList,
     ProfileEditor,
     RunHistory,
+    Compare,
+    Settings,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PendingNavigation {
+    SwitchView(ViewMode),
+    CreateNewProfile,
+    DuplicateSelectedProfile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnsavedChangesChoice {
+    Save,
+    Discard,
+    Cancel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExternalChangeChoice {
+    KeepMine,
+    Reload,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -72,6 +261,197 @@ pub enum PathType {
     Denied,
 }
 
+impl PathType {
+    pub fn opposite(self) -> Self {
+        match self {
+            PathType::Allowed => PathType::Denied,
+            PathType::Denied => PathType::Allowed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MoveDirection {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileSortOrder {
+    #[default]
+    NameAscending,
+    NameDescending,
+    RecentlyModified,
+}
+
+impl ProfileSortOrder {
+    pub const ALL: [ProfileSortOrder; 3] = [
+        ProfileSortOrder::NameAscending,
+        ProfileSortOrder::NameDescending,
+        ProfileSortOrder::RecentlyModified,
+    ];
+}
+
+impl std::fmt::Display for ProfileSortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileSortOrder::NameAscending => write!(f, "Name (A–Z)"),
+            ProfileSortOrder::NameDescending => write!(f, "Name (Z–A)"),
+            ProfileSortOrder::RecentlyModified => write!(f, "Recently modified"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HistorySortOrder {
+    #[default]
+    Newest,
+    Oldest,
+    Failures,
+}
+
+impl HistorySortOrder {
+    pub const ALL: [HistorySortOrder; 3] = [
+        HistorySortOrder::Newest,
+        HistorySortOrder::Oldest,
+        HistorySortOrder::Failures,
+    ];
+}
+
+impl std::fmt::Display for HistorySortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistorySortOrder::Newest => write!(f, "Newest first"),
+            HistorySortOrder::Oldest => write!(f, "Oldest first"),
+            HistorySortOrder::Failures => write!(f, "Failures first"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NetworkFilter {
+    #[default]
+    All,
+    Only(NetworkCapability),
+}
+
+impl NetworkFilter {
+    pub const ALL: [NetworkFilter; 5] = [
+        NetworkFilter::All,
+        NetworkFilter::Only(NetworkCapability::Disabled),
+        NetworkFilter::Only(NetworkCapability::Loopback),
+        NetworkFilter::Only(NetworkCapability::Outbound),
+        NetworkFilter::Only(NetworkCapability::Full),
+    ];
+
+    pub fn matches(&self, network: NetworkCapability) -> bool {
+        match self {
+            NetworkFilter::All => true,
+            NetworkFilter::Only(capability) => *capability == network,
+        }
+    }
+}
+
+impl std::fmt::Display for NetworkFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkFilter::All => write!(f, "All networks"),
+            NetworkFilter::Only(capability) => write!(f, "{}", capability),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileGrouping {
+    None,
+    Metadata(String),
+}
+
+impl ProfileGrouping {
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            ProfileGrouping::None => None,
+            ProfileGrouping::Metadata(key) => Some(key),
+        }
+    }
+}
+
+impl std::fmt::Display for ProfileGrouping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileGrouping::None => write!(f, "No grouping"),
+            ProfileGrouping::Metadata(key) => write!(f, "Group by {}", key),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkChange {
+    Network(NetworkCapability),
+    GrantFilesystem(FilesystemCapability),
+    RevokeFilesystem(FilesystemCapability),
+}
+
+impl BulkChange {
+    pub const ALL: [BulkChange; 10] = [
+        BulkChange::Network(NetworkCapability::Disabled),
+        BulkChange::Network(NetworkCapability::Loopback),
+        BulkChange::Network(NetworkCapability::Outbound),
+        BulkChange::Network(NetworkCapability::Full),
+        BulkChange::RevokeFilesystem(FilesystemCapability::Write),
+        BulkChange::RevokeFilesystem(FilesystemCapability::Execute),
+        BulkChange::RevokeFilesystem(FilesystemCapability::Read),
+        BulkChange::GrantFilesystem(FilesystemCapability::Read),
+        BulkChange::GrantFilesystem(FilesystemCapability::Write),
+        BulkChange::GrantFilesystem(FilesystemCapability::Execute),
+    ];
+
+    pub fn apply(&self, policy: &mut Policy) {
+        let capabilities = &mut policy.capabilities;
+        match *self {
+            BulkChange::Network(network) => {
+                if capabilities.network != network {
+                    capabilities.network = network;
+                    capabilities.dns = None;
+                }
+            }
+            BulkChange::GrantFilesystem(capability) => {
+                capabilities.filesystem.insert(capability);
+                for granted in capabilities.path_capabilities.values_mut() {
+                    granted.insert(capability);
+                }
+            }
+            BulkChange::RevokeFilesystem(capability) => {
+                capabilities.filesystem.remove(&capability);
+                for granted in capabilities.path_capabilities.values_mut() {
+                    granted.remove(&capability);
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for BulkChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BulkChange::Network(network) => write!(f, "Set network to {}", network),
+            BulkChange::GrantFilesystem(capability) => {
+                write!(f, "Grant {} on every path", capability.label())
+            }
+            BulkChange::RevokeFilesystem(capability) => {
+                write!(f, "Revoke {} on every path", capability.label())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BulkEdit {
+    pub change: BulkChange,
+    pub filtered_only: bool,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MemoryUnit {
     Bytes,
@@ -109,6 +489,23 @@ impl MemoryUnit {
     }
 }
 
+pub fn format_memory_value(bytes: u64, unit: MemoryUnit) -> String {
+    let decimals = match unit {
+        MemoryUnit::Bytes => 0,
+        MemoryUnit::KB | MemoryUnit::MB => 2,
+        MemoryUnit::GB => 3,
+    };
+    let formatted = format!("{:.*}", decimals, unit.from_bytes(bytes));
+    if formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        formatted
+    }
+}
+
 impl std::fmt::Display for MemoryUnit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -123,39 +520,172 @@ impl std::fmt::Display for MemoryUnit {
 #[derive(Debug)]
 pub enum Message {
     ProfilesLoaded(Vec<Policy>),
+    ProfilesChangedOnDisk,
+    ReloadProfiles,
+    ResolveExternalChange(ExternalChangeChoice),
     ProfileSelected(usize),
+    MoveProfileHighlight(MoveDirection),
+    OpenHighlightedProfile,
+    CompareLeftSelected(String),
+    CompareRightSelected(String),
     CreateNewProfile,
+    OpenTemplateGallery,
+    CloseTemplateGallery,
+    CreateFromTemplate(&'static ProfileTemplate),
+    OpenBulkEdit,
+    BulkChangeSelected(BulkChange),
+    BulkFilteredOnlyToggled(bool),
+    CancelBulkEdit,
+    ApplyBulkEdit,
+    RequestDeleteProfile(usize),
+    RequestDeleteSelectedProfile,
+    CancelDeleteProfile,
     DeleteProfile(usize),
+    UndoDelete,
+    UndoDeleteExpired(u64),
     DuplicateProfile(usize),
+    DuplicateSelectedProfile,
+    ImportProfile,
+    PasteProfile,
+    ExportAllProfiles,
+    ImportAllProfiles,
+    ProfileArchiveImported(Result<Option<Vec<Policy>>, String>),
+    ImportEnvFile,
+    EnvFileImported(Result<Option<Vec<(String, String)>>, String>),
+    ProfilePasted(Option<String>),
+    ProfileImported(Result<Option<Box<Policy>>, String>),
     NetworkCapabilityChanged(NetworkCapability),
     FilesystemCapabilityToggled(FilesystemCapability),
     PathInputChanged { path_type: PathType, value: String },
     AddPath { path_type: PathType },
     RemovePath { path_type: PathType, index: usize },
+    MovePath(PathType, usize, MoveDirection),
+    MovePathToOtherList { path_type: PathType, index: usize },
+    PathNoteChanged(PathType, usize, String),
+    PathFilterChanged(PathType, String),
+    DnsToggled(bool),
+    RootPathChanged(String),
+    BrowseRootPath,
+    RootPathPicked(Option<String>),
+    WorkingDirectoryChanged(String),
+    HostnameChanged(String),
+    OutboundEntryInputChanged(String),
+    AddOutboundEntries,
+    RemoveOutboundEntry(usize),
+    PathCapabilityToggled(usize, FilesystemCapability),
     CpuChanged(f32),
     MemoryBytesChanged(String),
     MemoryUnitChanged(MemoryUnit),
+    MemorySliderChanged(f32),
     MaxProcessesChanged(String),
     NameChanged(String),
     SaveProfile,
+    ProfileSaved(Result<(), String>),
+    ValidationFlashExpired(u64),
+    FocusValidationField(String),
+    ExportProfileJson,
+    ExportPolicySchema,
+    RestoreProfileBackup,
+    CopyProfileToml,
+    ShowPolicyPreview,
+    CopyPolicyPreview,
+    CopyProtoDebug,
+    ClosePolicyPreview,
+    ToggleSection(String),
+    ProfileExported(Result<Option<String>, String>),
+    DismissNotification,
+    NotificationExpired(u64),
     SwitchView(ViewMode),
+    ResolveUnsavedChanges(UnsavedChangesChoice),
     HistoryFilterChanged(String),
-    GrpcClientConnected(Result<GrpcClient, String>),
+    HistoryFilterDebounced(u64),
+    WindowResized(iced::Size),
+    WindowMoved(iced::Point),
+    SaveWindowGeometry(u64),
+    HistorySortChanged(HistorySortOrder),
+    HistoryDateFromChanged(String),
+    HistoryDateToChanged(String),
+    HistoryPageChanged(usize),
+    HistoryAutoRefreshToggled(bool),
+    HistoryRefreshIntervalChanged(String),
+    DefaultRunCommandChanged(String),
+    RefreshHistory,
+    RefreshStats,
+    StatsUpdated(Vec<(String, Result<ResourceUsage, String>)>, DaemonClient),
+    ShowSandboxDetails(String),
+    CloseSandboxDetails,
+    ExportHistoryCsv,
+    RequestClearHistory,
+    CancelClearHistory,
+    ClearHistory,
+    HistoryExported(Result<Option<String>, String>),
+    ProfileFilterChanged(String),
+    ProfileNetworkFilterChanged(NetworkFilter),
+    ProfileSortChanged(ProfileSortOrder),
+    ProfileGroupingChanged(ProfileGrouping),
+    ThemeChanged(AppTheme),
+    GrpcClientConnected(u64, Result<DaemonClient, String>),
+    DaemonVersionChecked(Option<String>, DaemonClient),
+    ConnectionModeChanged(ConnectionMode),
+    SocketPathInputChanged(String),
+    TcpAddressInputChanged(String),
+    ApplyConnectionSettings,
+    ReconnectDaemon,
+    RunCommandChanged(String),
     RunSandbox { profile_idx: usize, command: String },
-    RunSandboxResult(Result<String, String>, GrpcClient),
-    StopSandbox { sandbox_id: String },
-    StopSandboxResult(Result<(), String>, GrpcClient),
-    HistoryLoaded(Result<Vec<RunRecord>, String>, GrpcClient),
+    RunSandboxResult(Result<String, String>, SandboxLaunch, DaemonClient),
+    RerunSandbox(String),
+    RequestStopSandbox(String),
+    StopForceToggled(bool),
+    CancelStopSandbox,
+    StopSandbox { sandbox_id: String, force: bool },
+    CopySandboxId(String),
+    RequestStopAll,
+    CancelStopAll,
+    StopAll,
+    StopSandboxResult(Result<String, String>, DaemonClient),
+    HistoryLoaded(Result<Vec<RunRecord>, String>, DaemonClient),
+    StatusLoaded(Result<SandboxStatus, String>, DaemonClient),
 }
 
 impl Clone for Message {
     fn clone(&self) -> Self {
         match self {
             Message::ProfilesLoaded(p) => Message::ProfilesLoaded(p.clone()),
+            Message::ProfilesChangedOnDisk => Message::ProfilesChangedOnDisk,
+            Message::ReloadProfiles => Message::ReloadProfiles,
+            Message::ResolveExternalChange(c) => Message::ResolveExternalChange(*c),
             Message::ProfileSelected(i) => Message::ProfileSelected(*i),
+            Message::MoveProfileHighlight(d) => Message::MoveProfileHighlight(*d),
+            Message::OpenHighlightedProfile => Message::OpenHighlightedProfile,
+            Message::CompareLeftSelected(name) => Message::CompareLeftSelected(name.clone()),
+            Message::CompareRightSelected(name) => Message::CompareRightSelected(name.clone()),
             Message::CreateNewProfile => Message::CreateNewProfile,
+            Message::OpenTemplateGallery => Message::OpenTemplateGallery,
+            Message::CloseTemplateGallery => Message::CloseTemplateGallery,
+            Message::CreateFromTemplate(t) => Message::CreateFromTemplate(t),
+            Message::OpenBulkEdit => Message::OpenBulkEdit,
+            Message::BulkChangeSelected(c) => Message::BulkChangeSelected(*c),
+            Message::BulkFilteredOnlyToggled(b) => Message::BulkFilteredOnlyToggled(*b),
+            Message::CancelBulkEdit => Message::CancelBulkEdit,
+            Message::ApplyBulkEdit => Message::ApplyBulkEdit,
+            Message::RequestDeleteProfile(i) => Message::RequestDeleteProfile(*i),
+            Message::RequestDeleteSelectedProfile => Message::RequestDeleteSelectedProfile,
+            Message::CancelDeleteProfile => Message::CancelDeleteProfile,
             Message::DeleteProfile(i) => Message::DeleteProfile(*i),
+            Message::UndoDelete => Message::UndoDelete,
+            Message::UndoDeleteExpired(g) => Message::UndoDeleteExpired(*g),
             Message::DuplicateProfile(i) => Message::DuplicateProfile(*i),
+            Message::DuplicateSelectedProfile => Message::DuplicateSelectedProfile,
+            Message::ImportProfile => Message::ImportProfile,
+            Message::PasteProfile => Message::PasteProfile,
+            Message::ExportAllProfiles => Message::ExportAllProfiles,
+            Message::ImportAllProfiles => Message::ImportAllProfiles,
+            Message::ProfileArchiveImported(r) => Message::ProfileArchiveImported(r.clone()),
+            Message::ImportEnvFile => Message::ImportEnvFile,
+            Message::EnvFileImported(r) => Message::EnvFileImported(r.clone()),
+            Message::ProfilePasted(s) => Message::ProfilePasted(s.clone()),
+            Message::ProfileImported(r) => Message::ProfileImported(r.clone()),
             Message::NetworkCapabilityChanged(c) => Message::NetworkCapabilityChanged(*c),
             Message::FilesystemCapabilityToggled(c) => Message::FilesystemCapabilityToggled(*c),
             Message::PathInputChanged { path_type, value } => Message::PathInputChanged {
@@ -169,14 +699,81 @@ impl Clone for Message {
                 path_type: *path_type,
                 index: *index,
             },
+            Message::MovePath(t, i, d) => Message::MovePath(*t, *i, *d),
+            Message::MovePathToOtherList { path_type, index } => Message::MovePathToOtherList {
+                path_type: *path_type,
+                index: *index,
+            },
+            Message::PathNoteChanged(t, i, n) => Message::PathNoteChanged(*t, *i, n.clone()),
+            Message::PathFilterChanged(t, f) => Message::PathFilterChanged(*t, f.clone()),
+            Message::DnsToggled(b) => Message::DnsToggled(*b),
+            Message::RootPathChanged(s) => Message::RootPathChanged(s.clone()),
+            Message::BrowseRootPath => Message::BrowseRootPath,
+            Message::RootPathPicked(p) => Message::RootPathPicked(p.clone()),
+            Message::WorkingDirectoryChanged(s) => Message::WorkingDirectoryChanged(s.clone()),
+            Message::HostnameChanged(s) => Message::HostnameChanged(s.clone()),
+            Message::OutboundEntryInputChanged(s) => Message::OutboundEntryInputChanged(s.clone()),
+            Message::AddOutboundEntries => Message::AddOutboundEntries,
+            Message::RemoveOutboundEntry(i) => Message::RemoveOutboundEntry(*i),
+            Message::PathCapabilityToggled(i, c) => Message::PathCapabilityToggled(*i, *c),
             Message::CpuChanged(f) => Message::CpuChanged(*f),
             Message::MemoryBytesChanged(s) => Message::MemoryBytesChanged(s.clone()),
             Message::MemoryUnitChanged(u) => Message::MemoryUnitChanged(*u),
+            Message::MemorySliderChanged(gb) => Message::MemorySliderChanged(*gb),
             Message::MaxProcessesChanged(s) => Message::MaxProcessesChanged(s.clone()),
             Message::NameChanged(s) => Message::NameChanged(s.clone()),
             Message::SaveProfile => Message::SaveProfile,
+            Message::ProfileSaved(r) => Message::ProfileSaved(r.clone()),
+            Message::ValidationFlashExpired(g) => Message::ValidationFlashExpired(*g),
+            Message::FocusValidationField(f) => Message::FocusValidationField(f.clone()),
+            Message::ExportProfileJson => Message::ExportProfileJson,
+            Message::ExportPolicySchema => Message::ExportPolicySchema,
+            Message::RestoreProfileBackup => Message::RestoreProfileBackup,
+            Message::CopyProfileToml => Message::CopyProfileToml,
+            Message::ShowPolicyPreview => Message::ShowPolicyPreview,
+            Message::CopyPolicyPreview => Message::CopyPolicyPreview,
+            Message::CopyProtoDebug => Message::CopyProtoDebug,
+            Message::ClosePolicyPreview => Message::ClosePolicyPreview,
+            Message::ToggleSection(s) => Message::ToggleSection(s.clone()),
+            Message::ProfileExported(r) => Message::ProfileExported(r.clone()),
+            Message::DismissNotification => Message::DismissNotification,
+            Message::NotificationExpired(g) => Message::NotificationExpired(*g),
             Message::SwitchView(v) => Message::SwitchView(*v),
+            Message::ResolveUnsavedChanges(c) => Message::ResolveUnsavedChanges(*c),
             Message::HistoryFilterChanged(s) => Message::HistoryFilterChanged(s.clone()),
+            Message::HistoryFilterDebounced(g) => Message::HistoryFilterDebounced(*g),
+            Message::WindowResized(size) => Message::WindowResized(*size),
+            Message::WindowMoved(position) => Message::WindowMoved(*position),
+            Message::SaveWindowGeometry(g) => Message::SaveWindowGeometry(*g),
+            Message::HistorySortChanged(o) => Message::HistorySortChanged(*o),
+            Message::HistoryDateFromChanged(s) => Message::HistoryDateFromChanged(s.clone()),
+            Message::HistoryDateToChanged(s) => Message::HistoryDateToChanged(s.clone()),
+            Message::HistoryPageChanged(p) => Message::HistoryPageChanged(*p),
+            Message::HistoryAutoRefreshToggled(b) => Message::HistoryAutoRefreshToggled(*b),
+            Message::HistoryRefreshIntervalChanged(s) => {
+                Message::HistoryRefreshIntervalChanged(s.clone())
+            }
+            Message::DefaultRunCommandChanged(s) => Message::DefaultRunCommandChanged(s.clone()),
+            Message::RefreshHistory => Message::RefreshHistory,
+            Message::RefreshStats => Message::RefreshStats,
+            Message::ShowSandboxDetails(id) => Message::ShowSandboxDetails(id.clone()),
+            Message::CloseSandboxDetails => Message::CloseSandboxDetails,
+            Message::ExportHistoryCsv => Message::ExportHistoryCsv,
+            Message::RequestClearHistory => Message::RequestClearHistory,
+            Message::CancelClearHistory => Message::CancelClearHistory,
+            Message::ClearHistory => Message::ClearHistory,
+            Message::HistoryExported(r) => Message::HistoryExported(r.clone()),
+            Message::ProfileFilterChanged(s) => Message::ProfileFilterChanged(s.clone()),
+            Message::ProfileNetworkFilterChanged(f) => Message::ProfileNetworkFilterChanged(*f),
+            Message::ProfileSortChanged(o) => Message::ProfileSortChanged(*o),
+            Message::ProfileGroupingChanged(g) => Message::ProfileGroupingChanged(g.clone()),
+            Message::ThemeChanged(t) => Message::ThemeChanged(*t),
+            Message::ConnectionModeChanged(m) => Message::ConnectionModeChanged(*m),
+            Message::SocketPathInputChanged(s) => Message::SocketPathInputChanged(s.clone()),
+            Message::TcpAddressInputChanged(s) => Message::TcpAddressInputChanged(s.clone()),
+            Message::ApplyConnectionSettings => Message::ApplyConnectionSettings,
+            Message::ReconnectDaemon => Message::ReconnectDaemon,
+            Message::RunCommandChanged(s) => Message::RunCommandChanged(s.clone()),
             Message::RunSandbox {
                 profile_idx,
                 command,
@@ -184,44 +781,139 @@ impl Clone for Message {
                 profile_idx: *profile_idx,
                 command: command.clone(),
             },
-            Message::StopSandbox { sandbox_id } => Message::StopSandbox {
+            Message::RequestStopSandbox(id) => Message::RequestStopSandbox(id.clone()),
+            Message::StopForceToggled(force) => Message::StopForceToggled(*force),
+            Message::CancelStopSandbox => Message::CancelStopSandbox,
+            Message::StopSandbox { sandbox_id, force } => Message::StopSandbox {
                 sandbox_id: sandbox_id.clone(),
+                force: *force,
             },
+            Message::RerunSandbox(id) => Message::RerunSandbox(id.clone()),
+            Message::CopySandboxId(id) => Message::CopySandboxId(id.clone()),
+            Message::RequestStopAll => Message::RequestStopAll,
+            Message::CancelStopAll => Message::CancelStopAll,
+            Message::StopAll => Message::StopAll,
             _ => panic!("Cannot clone Message with GrpcClient"),
         }
     }
 }
 
 impl HopsGui {
-    pub fn new() -> (Self, Task<Message>) {
+    pub fn new(open_profile: Option<String>) -> (Self, Task<Message>) {
         let profiles = config::load_profiles().unwrap_or_default();
-        (
-            Self {
-                profiles,
-                selected_profile: None,
-                view_mode: ViewMode::ProfileList,
-                path_inputs: PathInputs::default(),
-                validation_errors: ValidationErrors::default(),
-                run_history: vec![],
-                history_filter: String::new(),
-                grpc_client: None,
-                daemon_status: DaemonStatus::Unknown,
-                loading_state: LoadingState::Idle,
-                memory_unit: MemoryUnit::MB,
-                memory_display_value: String::new(),
+        let mut app = Self {
+            profiles,
+            selected_profile: None,
+            dirty: false,
+            editor_snapshot: None,
+            saving: None,
+            pending_navigation: None,
+            navigation_after_save: None,
+            external_change: None,
+            pending_delete: None,
+            show_template_gallery: false,
+            bulk_edit: None,
+            collapsed: HashSet::new(),
+            last_deleted: None,
+            undo_generation: 0,
+            view_mode: ViewMode::ProfileList,
+            path_inputs: PathInputs::default(),
+            validation_errors: ValidationErrors::default(),
+            validation_flash: false,
+            notification_generation: 0,
+            validation_flash_generation: 0,
+            run_history: vec![],
+            history_filter: HistoryFilter::default(),
+            history_filter_generation: 0,
+            window_geometry_generation: 0,
+            history_sort: HistorySortOrder::default(),
+            history_page: 0,
+            sandbox_details: None,
+            sandbox_stats: HashMap::new(),
+            policy_preview: None,
+            pending_clear_history: false,
+            pending_stop: None,
+            pending_stop_all: false,
+            stop_all: None,
+            profile_filter: String::new(),
+            profile_network_filter: NetworkFilter::default(),
+            highlighted_profile: None,
+            compare_left: None,
+            compare_right: None,
+            settings: settings::load_settings(),
+            grpc_client: None,
+            connection_generation: 0,
+            daemon_status: DaemonStatus::Unknown,
+            connection_error: None,
+            version_mismatch: None,
+            connection_inputs: ConnectionInputs::default(),
+            loading_state: LoadingState::Idle,
+            notification: None,
+            memory_unit: MemoryUnit::MB,
+            memory_display_value: String::new(),
+            host_cores: std::thread::available_parallelism()
+                .map_or(MIN_CPU_SLIDER_MAX, |n| n.get() as u32)
+                .max(MIN_CPU_SLIDER_MAX),
+            run_command_input: String::new(),
+            run_command_error: None,
+            policy_issues: vec![],
+            validated_profile: None,
+        };
+        let mut tasks = vec![app.connect()];
+        if let Some(index) =
+            open_profile.and_then(|name| app.profiles.iter().position(|p| p.name == name))
+        {
+            tasks.push(app.update(Message::ProfileSelected(index)));
+        }
+        app.refresh_policy_issues();
+        (app, Task::batch(tasks))
+    }
+
+    fn connect(&self) -> Task<Message> {
+        let settings = self.settings.clone();
+        let generation = self.connection_generation;
+        Task::perform(
+            async move {
+                GrpcClient::connect_with_settings(&settings)
+                    .await
+                    .map(|client| DaemonClient { generation, client })
+                    .map_err(|e| e.to_string())
             },
-            Task::perform(
-                async {
-                    match GrpcClient::connect().await {
-                        Ok(client) => Ok(client),
-                        Err(e) => Err(e.to_string()),
-                    }
-                },
-                Message::GrpcClientConnected,
-            ),
+            move |result| Message::GrpcClientConnected(generation, result),
         )
     }
 
+    fn persist_settings(&mut self) {
+        if let Err(e) = settings::save_settings(&self.settings) {
+            self.notification = Some(Notification::error(format!(
+                "Failed to save settings: {}. Check that the Hops config directory is writable",
+                e
+            )));
+        }
+    }
+
+    fn restore_client(&mut self, client: DaemonClient) {
+        if client.generation == self.connection_generation {
+            self.grpc_client = Some(client);
+        }
+    }
+
+    pub fn refresh_policy_issues(&mut self) {
+        let connection = self.settings.connection_mode;
+        let profile = self.selected_profile.and_then(|idx| self.profiles.get(idx));
+        let validated = self
+            .validated_profile
+            .as_ref()
+            .map(|(policy, mode)| (policy, *mode));
+        if profile.map(|profile| (profile, connection)) == validated {
+            return;
+        }
+        self.policy_issues = profile
+            .map(|profile| profile.validate(connection))
+            .unwrap_or_default();
+        self.validated_profile = profile.map(|profile| (profile.clone(), connection));
+    }
+
     pub fn title(&self) -> String {
         String::from("Hops - Profile Management")
     }
@@ -229,34 +921,210 @@ impl HopsGui {
     pub fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::ProfilesLoaded(profiles) => {
-                self.profiles = profiles;
+                self.merge_loaded_profiles(profiles);
+            }
+            Message::ReloadProfiles => {
+                if self.dirty {
+                    if let Some(profile) =
+                        self.selected_profile.and_then(|idx| self.profiles.get(idx))
+                    {
+                        self.notification = Some(Notification::error(format!(
+                            "\"{}\" has unsaved edits; they were kept over the reloaded copy",
+                            profile.name
+                        )));
+                    }
+                }
+                return self.update(Message::ProfilesChangedOnDisk);
+            }
+            Message::ProfilesChangedOnDisk => {
+                return Task::perform(
+                    async { config::load_profiles().unwrap_or_default() },
+                    Message::ProfilesLoaded,
+                );
+            }
+            Message::ResolveExternalChange(choice) => {
+                let Some(external) = self.external_change.take() else {
+                    return Task::none();
+                };
+                match choice {
+                    ExternalChangeChoice::Reload => {
+                        self.forget_last_deleted();
+                        if let Some(profile) = self
+                            .selected_profile
+                            .and_then(|idx| self.profiles.get_mut(idx))
+                        {
+                            *profile = external.clone();
+                        }
+                        self.memory_display_value = external
+                            .capabilities
+                            .resource_limits
+                            .memory_bytes
+                            .map(|bytes| format_memory_value(bytes, self.memory_unit))
+                            .unwrap_or_default();
+                        self.path_inputs = PathInputs::default();
+                        self.validation_errors = ValidationErrors::default();
+                        self.dirty = false;
+                    }
+                    ExternalChangeChoice::KeepMine => {}
+                }
+                self.editor_snapshot = Some(external);
+            }
+            Message::MoveProfileHighlight(direction) => {
+                if self.view_mode != ViewMode::ProfileList || self.pending_delete.is_some() {
+                    return Task::none();
+                }
+                let visible: Vec<usize> = profile_list::visible_profiles(
+                    &self.profiles,
+                    &self.profile_filter,
+                    self.profile_network_filter,
+                    self.settings.profile_sort,
+                    self.settings.profile_group_by.as_deref(),
+                )
+                .into_iter()
+                .map(|(index, _)| index)
+                .collect();
+                let Some(last) = visible.len().checked_sub(1) else {
+                    self.highlighted_profile = None;
+                    return Task::none();
+                };
+                let position = self
+                    .highlighted_profile
+                    .and_then(|highlighted| visible.iter().position(|&i| i == highlighted));
+                let next = match (position, direction) {
+                    (None, MoveDirection::Down) => 0,
+                    (None, MoveDirection::Up) => last,
+                    (Some(position), MoveDirection::Down) => (position + 1).min(last),
+                    (Some(position), MoveDirection::Up) => position.saturating_sub(1),
+                };
+                self.highlighted_profile = Some(visible[next]);
+            }
+            Message::OpenHighlightedProfile => {
+                if self.view_mode == ViewMode::ProfileList && self.pending_delete.is_none() {
+                    if let Some(index) = self.highlighted_profile {
+                        if index < self.profiles.len() {
+                            return self.update(Message::ProfileSelected(index));
+                        }
+                    }
+                }
+            }
+            Message::CompareLeftSelected(name) => {
+                self.compare_left = Some(name);
+            }
+            Message::CompareRightSelected(name) => {
+                self.compare_right = Some(name);
             }
             Message::ProfileSelected(index) => {
+                self.highlighted_profile = Some(index);
                 self.selected_profile = Some(index);
+                self.run_command_input = self
+                    .profiles
+                    .get(index)
+                    .and_then(|p| self.settings.recent_commands_for(&p.name).first())
+                    .or(self.settings.default_run_command.as_ref())
+                    .cloned()
+                    .unwrap_or_default();
+                self.run_command_error = None;
+                self.dirty = false;
+                self.editor_snapshot = self.profiles.get(index).cloned();
                 self.view_mode = ViewMode::ProfileEditor;
                 self.path_inputs = PathInputs::default();
                 self.validation_errors = ValidationErrors::default();
                 if let Some(profile) = self.profiles.get(index) {
                     if let Some(bytes) = profile.capabilities.resource_limits.memory_bytes {
-                        self.memory_display_value = self.memory_unit.from_bytes(bytes).to_string();
+                        self.memory_display_value = format_memory_value(bytes, self.memory_unit);
                     } else {
                         self.memory_display_value = String::new();
                     }
                 }
             }
             Message::CreateNewProfile => {
+                if self.has_unsaved_changes() {
+                    self.pending_navigation = Some(PendingNavigation::CreateNewProfile);
+                    return Task::none();
+                }
                 let mut new_policy = Policy::default();
                 new_policy.name = format!("profile-{}", self.profiles.len() + 1);
-                self.profiles.push(new_policy);
-                self.selected_profile = Some(self.profiles.len() - 1);
-                self.view_mode = ViewMode::ProfileEditor;
-                self.path_inputs = PathInputs::default();
-                self.validation_errors = ValidationErrors::default();
-                self.memory_display_value = String::new();
+                self.open_new_profile(new_policy);
+            }
+            Message::OpenTemplateGallery => {
+                self.show_template_gallery = true;
+            }
+            Message::CloseTemplateGallery => {
+                self.show_template_gallery = false;
+            }
+            Message::CreateFromTemplate(template) => {
+                self.show_template_gallery = false;
+                let mut new_policy = template.policy();
+                new_policy.name = self.unique_profile_name(template.slug);
+                self.open_new_profile(new_policy);
+            }
+            Message::OpenBulkEdit => {
+                self.bulk_edit = Some(BulkEdit {
+                    change: BulkChange::Network(NetworkCapability::Disabled),
+                    filtered_only: false,
+                });
+            }
+            Message::BulkChangeSelected(change) => {
+                if let Some(edit) = self.bulk_edit.as_mut() {
+                    edit.change = change;
+                }
+            }
+            Message::BulkFilteredOnlyToggled(filtered_only) => {
+                if let Some(edit) = self.bulk_edit.as_mut() {
+                    edit.filtered_only = filtered_only;
+                }
+            }
+            Message::CancelBulkEdit => {
+                self.bulk_edit = None;
+            }
+            Message::ApplyBulkEdit => {
+                if let Some(edit) = self.bulk_edit.take() {
+                    self.forget_last_deleted();
+                    self.apply_bulk_edit(edit);
+                }
+            }
+            Message::RequestDeleteProfile(index) => {
+                if index < self.profiles.len() {
+                    self.pending_delete = Some(index);
+                }
+            }
+            Message::RequestDeleteSelectedProfile => {
+                if self.view_mode == ViewMode::ProfileEditor && self.pending_navigation.is_none() {
+                    if let Some(index) = self.selected_profile {
+                        return self.update(Message::RequestDeleteProfile(index));
+                    }
+                }
+            }
+            Message::CancelDeleteProfile => {
+                self.pending_delete = None;
             }
             Message::DeleteProfile(index) => {
+                self.pending_delete = None;
+                self.highlighted_profile = None;
                 if index < self.profiles.len() {
-                    self.profiles.remove(index);
+                    let snapshot = match config::read_profile_file(&self.profiles[index].name) {
+                        Ok(snapshot) => snapshot,
+                        Err(e) => {
+                            self.notification = Some(Notification::error(format!(
+                                "Failed to delete \"{}\": could not read the profile file to keep for undo ({})",
+                                self.profiles[index].name, e
+                            )));
+                            return Task::none();
+                        }
+                    };
+                    if let Err(e) = config::delete_profile(&self.profiles[index].name) {
+                        self.notification = Some(Notification::error(format!(
+                            "Failed to delete \"{}\": {}",
+                            self.profiles[index].name, e
+                        )));
+                        return Task::none();
+                    }
+                    let removed = self.profiles.remove(index);
+                    self.last_deleted = snapshot.map(|content| DeletedProfile {
+                        name: removed.name,
+                        content,
+                    });
+                    self.undo_generation += 1;
                     if let Some(selected) = self.selected_profile {
                         if selected == index {
                             self.selected_profile = None;
@@ -265,19 +1133,301 @@ impl HopsGui {
                             self.selected_profile = Some(selected - 1);
                         }
                     }
+                    let generation = self.undo_generation;
+                    return Task::perform(tokio::time::sleep(UNDO_DELETE_TIMEOUT), move |_| {
+                        Message::UndoDeleteExpired(generation)
+                    });
+                }
+            }
+            Message::UndoDelete => {
+                if let Some(deleted) = self.last_deleted.take() {
+                    let original_name = deleted.name.clone();
+                    let name = self.unique_profile_name(&original_name);
+                    let policy = match config::restore_profile(&name, &deleted.content) {
+                        Ok(policy) => policy,
+                        Err(e) => {
+                            self.notification = Some(Notification::error(format!(
+                                "Failed to restore \"{}\": {}",
+                                original_name, e
+                            )));
+                            self.last_deleted = Some(deleted);
+                            return Task::none();
+                        }
+                    };
+                    self.notification = Some(if policy.name == original_name {
+                        Notification::success(format!("Restored \"{}\"", policy.name))
+                    } else {
+                        Notification::success(format!(
+                            "Restored \"{}\" as \"{}\" because the name is taken",
+                            original_name, policy.name
+                        ))
+                    });
+                    self.profiles.push(policy);
+                }
+            }
+            Message::UndoDeleteExpired(generation) => {
+                if generation == self.undo_generation {
+                    self.last_deleted = None;
                 }
             }
             Message::DuplicateProfile(index) => {
-                if let Some(profile) = self.profiles.get(index).cloned() {
-                    let mut new_profile = profile;
-                    new_profile.name = format!("{}-copy", new_profile.name);
-                    self.profiles.push(new_profile);
+                if let Some(mut new_profile) = self.duplicate_of(index) {
+                    match config::save_profile(&new_profile.name, &new_profile) {
+                        Ok(()) => {
+                            self.notification = Some(Notification::success(format!(
+                                "Duplicated as \"{}\"",
+                                new_profile.name
+                            )));
+                            new_profile.modified = Some(SystemTime::now());
+                            self.profiles.push(new_profile);
+                        }
+                        Err(e) => {
+                            self.notification =
+                                Some(Notification::error(format!("Duplicate failed: {}", e)));
+                        }
+                    }
+                }
+            }
+            Message::DuplicateSelectedProfile => {
+                if self.view_mode != ViewMode::ProfileEditor || self.pending_navigation.is_some() {
+                    return Task::none();
+                }
+                if self.has_unsaved_changes() {
+                    self.pending_navigation = Some(PendingNavigation::DuplicateSelectedProfile);
+                    return Task::none();
+                }
+                if let Some(new_profile) = self.selected_profile.and_then(|i| self.duplicate_of(i))
+                {
+                    self.open_new_profile(new_profile);
+                }
+            }
+            Message::PasteProfile => {
+                return iced::clipboard::read().map(Message::ProfilePasted);
+            }
+            Message::ExportAllProfiles => {
+                let archive = match config::profiles_to_json_archive(&self.profiles) {
+                    Ok(archive) => archive,
+                    Err(e) => {
+                        self.notification =
+                            Some(Notification::error(format!("Export failed: {}", e)));
+                        return Task::none();
+                    }
+                };
+                return Task::perform(
+                    async move {
+                        let Some(handle) = rfd::AsyncFileDialog::new()
+                            .set_file_name("hops-profiles.json")
+                            .add_filter("JSON", &["json"])
+                            .save_file()
+                            .await
+                        else {
+                            return Ok(None);
+                        };
+                        let path = handle.path().to_path_buf();
+                        tokio::fs::write(&path, archive)
+                            .await
+                            .map(|_| Some(path.display().to_string()))
+                            .map_err(|e| e.to_string())
+                    },
+                    Message::ProfileExported,
+                );
+            }
+            Message::ImportAllProfiles => {
+                return Task::perform(
+                    async {
+                        let Some(handle) = rfd::AsyncFileDialog::new()
+                            .add_filter("JSON", &["json"])
+                            .pick_file()
+                            .await
+                        else {
+                            return Ok(None);
+                        };
+                        let content = tokio::fs::read_to_string(handle.path())
+                            .await
+                            .map_err(|e| e.to_string())?;
+                        config::parse_profile_archive(&content).map(Some)
+                    },
+                    Message::ProfileArchiveImported,
+                );
+            }
+            Message::ProfileArchiveImported(result) => match result {
+                Ok(Some(policies)) => {
+                    let total = policies.len();
+                    let mut imported = 0;
+                    let mut renamed = 0;
+                    let mut failed = Vec::new();
+                    for mut policy in policies {
+                        let original = policy.name.clone();
+                        if config::validate_profile_name(&original).is_err() {
+                            failed.push(original);
+                            continue;
+                        }
+                        policy.name = self.unique_profile_name(&original);
+                        if let Err(e) = config::save_profile(&policy.name, &policy) {
+                            failed.push(format!("{} ({})", original, e));
+                            continue;
+                        }
+                        if policy.name != original {
+                            renamed += 1;
+                        }
+                        policy.modified = Some(SystemTime::now());
+                        self.profiles.push(policy);
+                        imported += 1;
+                    }
+                    self.notification = Some(if failed.is_empty() {
+                        Notification::success(format!(
+                            "Imported {} of {} profiles ({} renamed)",
+                            imported, total, renamed
+                        ))
+                    } else {
+                        Notification::error(format!(
+                            "Imported {} of {} profiles; skipped {}",
+                            imported,
+                            total,
+                            failed.join(", ")
+                        ))
+                    });
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.notification = Some(Notification::error(format!(
+                        "Could not import profiles: {}",
+                        e
+                    )));
+                }
+            },
+            Message::ProfilePasted(contents) => {
+                let parsed = contents
+                    .filter(|c| !c.trim().is_empty())
+                    .ok_or_else(|| "Clipboard is empty".to_string())
+                    .and_then(|c| config::parse_profile_text(&c));
+                match parsed {
+                    Ok(mut policy) => {
+                        let base = if policy.name.trim().is_empty() {
+                            "pasted-profile".to_string()
+                        } else {
+                            policy.name.clone()
+                        };
+                        policy.name = self.unique_profile_name(&base);
+                        self.open_new_profile(policy);
+                    }
+                    Err(e) => {
+                        self.notification = Some(Notification::error(format!(
+                            "Could not paste profile: {}",
+                            e
+                        )));
+                    }
+                }
+            }
+            Message::ImportProfile => {
+                return Task::perform(
+                    async {
+                        let Some(handle) = rfd::AsyncFileDialog::new()
+                            .add_filter("Profile", &["toml", "json"])
+                            .pick_file()
+                            .await
+                        else {
+                            return Ok(None);
+                        };
+                        config::import_profile(handle.path())
+                            .map(|policy| Some(Box::new(policy)))
+                            .map_err(|e| e.to_string())
+                    },
+                    Message::ProfileImported,
+                );
+            }
+            Message::ProfileImported(result) => match result {
+                Ok(Some(mut policy)) => {
+                    policy.name = self.unique_profile_name(&policy.name);
+                    match config::save_profile(&policy.name, &policy) {
+                        Ok(()) => {
+                            self.notification = Some(Notification::success(format!(
+                                "Imported profile \"{}\"",
+                                policy.name
+                            )));
+                            policy.modified = Some(SystemTime::now());
+                            self.profiles.push(*policy);
+                        }
+                        Err(e) => {
+                            self.notification =
+                                Some(Notification::error(format!("Import failed: {}", e)));
+                        }
+                    }
                 }
+                Ok(None) => {}
+                Err(e) => {
+                    self.notification = Some(Notification::error(format!(
+                        "Could not import profile: {}",
+                        e
+                    )));
+                }
+            },
+            Message::ImportEnvFile => {
+                return Task::perform(
+                    async {
+                        let Some(handle) = rfd::AsyncFileDialog::new().pick_file().await else {
+                            return Ok(None);
+                        };
+                        let content = tokio::fs::read_to_string(handle.path())
+                            .await
+                            .map_err(|e| e.to_string())?;
+                        config::parse_env_file(&content).map(Some)
+                    },
+                    Message::EnvFileImported,
+                );
             }
+            Message::EnvFileImported(result) => match result {
+                Ok(Some(entries)) => {
+                    let Some(profile) = self
+                        .selected_profile
+                        .and_then(|idx| self.profiles.get_mut(idx))
+                    else {
+                        return Task::none();
+                    };
+                    let environment = &mut profile.sandbox.environment;
+                    let mut conflicts = Vec::new();
+                    let mut changed = 0;
+                    for (key, value) in entries {
+                        match environment.insert(key.clone(), value.clone()) {
+                            Some(previous) if previous == value => {}
+                            Some(_) => {
+                                conflicts.push(key);
+                                changed += 1;
+                            }
+                            None => changed += 1,
+                        }
+                    }
+                    if changed > 0 {
+                        self.dirty = true;
+                    }
+                    self.notification = Some(if conflicts.is_empty() {
+                        Notification::success(format!(
+                            "Imported {} environment variable{}",
+                            changed,
+                            if changed == 1 { "" } else { "s" }
+                        ))
+                    } else {
+                        conflicts.sort();
+                        Notification::error(format!(
+                            "Imported {} environment variable{}, overwriting {}",
+                            changed,
+                            if changed == 1 { "" } else { "s" },
+                            conflicts.join(", ")
+                        ))
+                    });
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.notification =
+                        Some(Notification::error(format!("Could not import .env: {}", e)));
+                }
+            },
             Message::NetworkCapabilityChanged(capability) => {
                 if let Some(idx) = self.selected_profile {
                     if let Some(profile) = self.profiles.get_mut(idx) {
                         profile.capabilities.network = capability;
+                        profile.capabilities.dns = None;
+                        self.dirty = true;
                     }
                 }
             }
@@ -289,6 +1439,7 @@ impl HopsGui {
                         } else {
                             profile.capabilities.filesystem.insert(capability);
                         }
+                        self.dirty = true;
                     }
                 }
             }
@@ -296,35 +1447,101 @@ impl HopsGui {
                 PathType::Allowed => self.path_inputs.allowed_input = value,
                 PathType::Denied => self.path_inputs.denied_input = value,
             },
-            Message::AddPath { path_type } => {
-                let path = match path_type {
-                    PathType::Allowed => &self.path_inputs.allowed_input,
-                    PathType::Denied => &self.path_inputs.denied_input,
-                };
-
-                if path.trim().is_empty() {
-                    let field_name = format!("{:?}_path", path_type);
-                    self.validation_errors
-                        .fields
-                        .insert(field_name, "Path cannot be empty".to_string());
-                } else {
-                    let field_name = format!("{:?}_path", path_type);
-                    self.validation_errors.fields.remove(&field_name);
-
-                    if let Some(idx) = self.selected_profile {
-                        if let Some(profile) = self.profiles.get_mut(idx) {
-                            match path_type {
-                                PathType::Allowed => {
-                                    profile.capabilities.allowed_paths.push(path.clone());
-                                    self.path_inputs.allowed_input.clear();
-                                }
-                                PathType::Denied => {
-                                    profile.capabilities.denied_paths.push(path.clone());
-                                    self.path_inputs.denied_input.clear();
-                                }
-                            }
+            Message::PathFilterChanged(path_type, value) => match path_type {
+                PathType::Allowed => self.path_inputs.allowed_filter = value,
+                PathType::Denied => self.path_inputs.denied_filter = value,
+            },
+            Message::AddPath { path_type } => self.add_paths(path_type),
+            Message::DnsToggled(enabled) => {
+                if let Some(profile) = self
+                    .selected_profile
+                    .and_then(|idx| self.profiles.get_mut(idx))
+                {
+                    profile.capabilities.dns = Some(enabled);
+                    self.dirty = true;
+                }
+            }
+            Message::RootPathChanged(value) => {
+                if let Some(profile) = self
+                    .selected_profile
+                    .and_then(|idx| self.profiles.get_mut(idx))
+                {
+                    let error = if value.trim().is_empty() {
+                        Some("Root path cannot be empty")
+                    } else if !value.starts_with('/') {
+                        Some("Root path must be absolute")
+                    } else {
+                        None
+                    };
+                    match error {
+                        Some(error) => {
+                            self.validation_errors
+                                .fields
+                                .insert("root_path".to_string(), error.to_string());
+                        }
+                        None => {
+                            self.validation_errors.fields.remove("root_path");
                         }
                     }
+                    profile.sandbox.root_path = value;
+                    self.dirty = true;
+                }
+            }
+            Message::BrowseRootPath => {
+                return Task::perform(
+                    async {
+                        rfd::AsyncFileDialog::new()
+                            .pick_folder()
+                            .await
+                            .map(|handle| handle.path().display().to_string())
+                    },
+                    Message::RootPathPicked,
+                );
+            }
+            Message::RootPathPicked(path) => {
+                if let Some(path) = path {
+                    return self.update(Message::RootPathChanged(path));
+                }
+            }
+            Message::WorkingDirectoryChanged(value) => {
+                if let Some(profile) = self
+                    .selected_profile
+                    .and_then(|idx| self.profiles.get_mut(idx))
+                {
+                    profile.sandbox.working_directory = value;
+                    self.dirty = true;
+                }
+            }
+            Message::HostnameChanged(value) => {
+                if let Some(profile) = self
+                    .selected_profile
+                    .and_then(|idx| self.profiles.get_mut(idx))
+                {
+                    match capability::validate_hostname(&value) {
+                        Ok(()) => {
+                            self.validation_errors.fields.remove("hostname");
+                        }
+                        Err(error) => {
+                            self.validation_errors
+                                .fields
+                                .insert("hostname".to_string(), error);
+                        }
+                    }
+                    profile.sandbox.hostname = Some(value).filter(|v| !v.is_empty());
+                    self.dirty = true;
+                }
+            }
+            Message::OutboundEntryInputChanged(value) => self.path_inputs.outbound_input = value,
+            Message::AddOutboundEntries => self.add_outbound_entries(),
+            Message::RemoveOutboundEntry(index) => {
+                if let Some(profile) = self
+                    .selected_profile
+                    .and_then(|idx| self.profiles.get_mut(idx))
+                {
+                    if index < profile.capabilities.outbound_allowlist.len() {
+                        profile.capabilities.outbound_allowlist.remove(index);
+                        self.dirty = true;
+                    }
                 }
             }
             Message::RemovePath { path_type, index } => {
@@ -334,21 +1551,74 @@ impl HopsGui {
                             PathType::Allowed => {
                                 if index < profile.capabilities.allowed_paths.len() {
                                     profile.capabilities.allowed_paths.remove(index);
+                                    profile.capabilities.prune_path_capabilities();
+                                    self.dirty = true;
                                 }
                             }
                             PathType::Denied => {
                                 if index < profile.capabilities.denied_paths.len() {
                                     profile.capabilities.denied_paths.remove(index);
+                                    self.dirty = true;
                                 }
                             }
                         }
                     }
                 }
             }
+            Message::MovePathToOtherList { path_type, index } => {
+                self.move_path_to_other_list(path_type, index);
+            }
+            Message::PathNoteChanged(path_type, index, note) => {
+                if let Some(idx) = self.selected_profile {
+                    if let Some(profile) = self.profiles.get_mut(idx) {
+                        let paths = match path_type {
+                            PathType::Allowed => &mut profile.capabilities.allowed_paths,
+                            PathType::Denied => &mut profile.capabilities.denied_paths,
+                        };
+                        if let Some(entry) = paths.get_mut(index) {
+                            entry.note = Some(note).filter(|note| !note.trim().is_empty());
+                            self.dirty = true;
+                        }
+                    }
+                }
+            }
+            Message::MovePath(path_type, index, direction) => {
+                if let Some(idx) = self.selected_profile {
+                    if let Some(profile) = self.profiles.get_mut(idx) {
+                        let paths = match path_type {
+                            PathType::Allowed => &mut profile.capabilities.allowed_paths,
+                            PathType::Denied => &mut profile.capabilities.denied_paths,
+                        };
+                        let target = match direction {
+                            MoveDirection::Up => index.checked_sub(1),
+                            MoveDirection::Down => Some(index + 1),
+                        };
+                        if let Some(target) = target.filter(|&t| t < paths.len()) {
+                            if index < paths.len() {
+                                paths.swap(index, target);
+                                self.dirty = true;
+                            }
+                        }
+                    }
+                }
+            }
+            Message::PathCapabilityToggled(index, capability) => {
+                if let Some(idx) = self.selected_profile {
+                    if let Some(profile) = self.profiles.get_mut(idx) {
+                        if let Some(path) = profile.capabilities.allowed_paths.get(index).cloned() {
+                            profile
+                                .capabilities
+                                .toggle_path_capability(&path.path, capability);
+                            self.dirty = true;
+                        }
+                    }
+                }
+            }
             Message::CpuChanged(cpus) => {
                 if let Some(idx) = self.selected_profile {
                     if let Some(profile) = self.profiles.get_mut(idx) {
                         profile.capabilities.resource_limits.cpus = Some(cpus as u32);
+                        self.dirty = true;
                     }
                 }
             }
@@ -356,13 +1626,15 @@ impl HopsGui {
                 self.memory_display_value = value.clone();
                 if let Some(idx) = self.selected_profile {
                     if let Some(profile) = self.profiles.get_mut(idx) {
-                        if let Ok(numeric_value) = value.parse::<f64>() {
+                        if let Ok(numeric_value) = value.trim().parse::<f64>() {
                             let bytes = self.memory_unit.to_bytes(numeric_value);
                             profile.capabilities.resource_limits.memory_bytes = Some(bytes);
                             self.validation_errors.fields.remove("memory_bytes");
-                        } else if value.is_empty() {
+                            self.dirty = true;
+                        } else if value.trim().is_empty() {
                             profile.capabilities.resource_limits.memory_bytes = None;
                             self.validation_errors.fields.remove("memory_bytes");
+                            self.dirty = true;
                         } else {
                             self.validation_errors.fields.insert(
                                 "memory_bytes".to_string(),
@@ -376,18 +1648,52 @@ impl HopsGui {
                 self.memory_unit = unit;
                 if let Some(idx) = self.selected_profile {
                     if let Some(profile) = self.profiles.get(idx) {
-                        if let Some(bytes) = profile.capabilities.resource_limits.memory_bytes {
-                            self.memory_display_value = unit.from_bytes(bytes).to_string();
+                        self.memory_display_value = profile
+                            .capabilities
+                            .resource_limits
+                            .memory_bytes
+                            .map_or_else(String::new, |bytes| format_memory_value(bytes, unit));
+                        self.validation_errors.fields.remove("memory_bytes");
+                    }
+                }
+            }
+            Message::MemorySliderChanged(gb) => {
+                if let Some(idx) = self.selected_profile {
+                    if let Some(profile) = self.profiles.get_mut(idx) {
+                        let bytes = MemoryUnit::GB.to_bytes(gb as f64);
+                        if bytes == 0 {
+                            profile.capabilities.resource_limits.memory_bytes = None;
+                            self.memory_display_value = String::new();
+                        } else {
+                            profile.capabilities.resource_limits.memory_bytes = Some(bytes);
+                            self.memory_display_value =
+                                format_memory_value(bytes, self.memory_unit);
                         }
+                        self.validation_errors.fields.remove("memory_bytes");
+                        self.dirty = true;
                     }
                 }
             }
             Message::MaxProcessesChanged(value) => {
                 if let Some(idx) = self.selected_profile {
                     if let Some(profile) = self.profiles.get_mut(idx) {
+                        let limit = self.settings.max_processes_limit();
                         if let Ok(max) = value.parse::<u32>() {
-                            profile.capabilities.resource_limits.max_processes = Some(max);
-                            self.validation_errors.fields.remove("max_processes");
+                            if max == 0 {
+                                self.validation_errors.fields.insert(
+                                    "max_processes".to_string(),
+                                    "Must be at least 1".to_string(),
+                                );
+                            } else if max > limit {
+                                self.validation_errors.fields.insert(
+                                    "max_processes".to_string(),
+                                    format!("Must be at most {}", limit),
+                                );
+                            } else {
+                                profile.capabilities.resource_limits.max_processes = Some(max);
+                                self.validation_errors.fields.remove("max_processes");
+                                self.dirty = true;
+                            }
                         } else {
                             self.validation_errors.fields.insert(
                                 "max_processes".to_string(),
@@ -399,176 +1705,1642 @@ impl HopsGui {
             }
             Message::NameChanged(name) => {
                 if let Some(idx) = self.selected_profile {
+                    let name_taken = self
+                        .profiles
+                        .iter()
+                        .enumerate()
+                        .any(|(i, p)| i != idx && p.name == name);
                     if let Some(profile) = self.profiles.get_mut(idx) {
                         if name.trim().is_empty() {
                             self.validation_errors
                                 .fields
                                 .insert("name".to_string(), "Name cannot be empty".to_string());
                         } else {
-                            self.validation_errors.fields.remove("name");
+                            if let Err(error) = config::validate_profile_name(&name) {
+                                self.validation_errors
+                                    .fields
+                                    .insert("name".to_string(), error);
+                            } else if name_taken {
+                                self.validation_errors.fields.insert(
+                                    "name".to_string(),
+                                    "A profile with this name already exists".to_string(),
+                                );
+                            } else {
+                                self.validation_errors.fields.remove("name");
+                            }
                             profile.name = name;
+                            self.dirty = true;
                         }
                     }
                 }
             }
             Message::SaveProfile => {
-                if self.validation_errors.fields.is_empty() {
-                    if let Some(idx) = self.selected_profile {
-                        if let Some(profile) = self.profiles.get(idx) {
-                            let _ = config::save_profile(&profile.name, profile);
+                if self.saving.is_some() {
+                    return Task::none();
+                }
+                if let Some(policy) = self.profile_to_save() {
+                    self.saving = Some(policy.clone());
+                    return Task::perform(
+                        async move {
+                            tokio::task::spawn_blocking(move || {
+                                config::save_profile(&policy.name, &policy)
+                            })
+                            .await
+                            .map_err(|e| e.to_string())?
+                            .map_err(|e| e.to_string())
+                        },
+                        Message::ProfileSaved,
+                    );
+                }
+                if !self.validation_errors.fields.is_empty() {
+                    self.validation_flash = true;
+                    self.validation_flash_generation += 1;
+                    let generation = self.validation_flash_generation;
+                    return Task::perform(
+                        tokio::time::sleep(VALIDATION_FLASH_DURATION),
+                        move |_| Message::ValidationFlashExpired(generation),
+                    );
+                }
+            }
+            Message::ProfileSaved(result) => {
+                let Some(saved) = self.saving.take() else {
+                    return Task::none();
+                };
+                let navigation = self.navigation_after_save.take();
+                match result {
+                    Ok(()) => {
+                        self.notification =
+                            Some(Notification::success(format!("Saved \"{}\"", saved.name)));
+                        self.finish_save(saved);
+                        self.notification_generation += 1;
+                        let generation = self.notification_generation;
+                        let toast =
+                            Task::perform(tokio::time::sleep(SAVED_TOAST_DURATION), move |_| {
+                                Message::NotificationExpired(generation)
+                            });
+                        return match navigation {
+                            Some(navigation) => Task::batch([self.navigate(navigation), toast]),
+                            None => toast,
+                        };
+                    }
+                    Err(e) => {
+                        self.notification =
+                            Some(Notification::error(format!("Save failed: {}", e)));
+                    }
+                }
+            }
+            Message::ValidationFlashExpired(generation) => {
+                if generation == self.validation_flash_generation {
+                    self.validation_flash = false;
+                }
+            }
+            Message::FocusValidationField(field) => {
+                if let Some(section) = profile_editor::field_section(&field) {
+                    self.collapsed.remove(section);
+                }
+                return profile_editor::scroll_to_field(&field);
+            }
+            Message::ToggleSection(section) => {
+                if !self.collapsed.remove(&section) {
+                    self.collapsed.insert(section);
+                }
+            }
+            Message::ExportProfileJson => {
+                if let Some(profile) = self.selected_profile.and_then(|i| self.profiles.get(i)) {
+                    let file_name = format!("{}.json", profile.name);
+                    let json = match config::profile_to_json(profile) {
+                        Ok(json) => json,
+                        Err(e) => {
+                            self.notification =
+                                Some(Notification::error(format!("Export failed: {}", e)));
+                            return Task::none();
+                        }
+                    };
+                    return Task::perform(
+                        async move {
+                            let Some(handle) = rfd::AsyncFileDialog::new()
+                                .set_file_name(file_name)
+                                .add_filter("JSON", &["json"])
+                                .save_file()
+                                .await
+                            else {
+                                return Ok(None);
+                            };
+                            let path = handle.path().to_path_buf();
+                            tokio::fs::write(&path, json)
+                                .await
+                                .map(|_| Some(path.display().to_string()))
+                                .map_err(|e| e.to_string())
+                        },
+                        Message::ProfileExported,
+                    );
+                }
+            }
+            Message::RestoreProfileBackup => {
+                let Some(idx) = self.selected_profile else {
+                    return Task::none();
+                };
+                let Some(name) = self
+                    .editor_snapshot
+                    .as_ref()
+                    .map(|snapshot| snapshot.name.clone())
+                else {
+                    self.notification =
+                        Some(Notification::error("This profile has not been saved yet"));
+                    return Task::none();
+                };
+                match config::load_latest_backup(&name) {
+                    Ok(Some(mut backup)) => {
+                        if let Some(profile) = self.profiles.get_mut(idx) {
+                            backup.name = profile.name.clone();
+                            self.memory_display_value = backup
+                                .capabilities
+                                .resource_limits
+                                .memory_bytes
+                                .map(|bytes| format_memory_value(bytes, self.memory_unit))
+                                .unwrap_or_default();
+                            *profile = backup;
+                            self.path_inputs = PathInputs::default();
+                            self.validation_errors = ValidationErrors::default();
+                            self.dirty = true;
+                            self.notification = Some(Notification::success(
+                                "Restored the last backup. Save to keep it.",
+                            ));
+                        }
+                    }
+                    Ok(None) => {
+                        self.notification = Some(Notification::error(format!(
+                            "No backups found for \"{}\"",
+                            name
+                        )));
+                    }
+                    Err(e) => {
+                        self.notification =
+                            Some(Notification::error(format!("Restore failed: {}", e)));
+                    }
+                }
+            }
+            Message::ExportPolicySchema => {
+                let schema = match config::policy_schema_json() {
+                    Ok(schema) => schema,
+                    Err(e) => {
+                        self.notification =
+                            Some(Notification::error(format!("Export failed: {}", e)));
+                        return Task::none();
+                    }
+                };
+                return Task::perform(
+                    async move {
+                        let Some(handle) = rfd::AsyncFileDialog::new()
+                            .set_file_name("policy.schema.json")
+                            .add_filter("JSON Schema", &["json"])
+                            .save_file()
+                            .await
+                        else {
+                            return Ok(None);
+                        };
+                        let path = handle.path().to_path_buf();
+                        tokio::fs::write(&path, schema)
+                            .await
+                            .map(|_| Some(path.display().to_string()))
+                            .map_err(|e| e.to_string())
+                    },
+                    Message::ProfileExported,
+                );
+            }
+            Message::CopyProfileToml => {
+                if let Some(profile) = self.selected_profile.and_then(|i| self.profiles.get(i)) {
+                    match config::profile_to_toml(profile) {
+                        Ok(toml_content) => {
+                            self.notification = Some(Notification::success(format!(
+                                "Copied \"{}\" to the clipboard",
+                                profile.name
+                            )));
+                            return iced::clipboard::write(format!(
+                                "# {}\n{}",
+                                profile.name, toml_content
+                            ));
+                        }
+                        Err(e) => {
+                            self.notification =
+                                Some(Notification::error(format!("Copy failed: {}", e)));
                         }
                     }
                 }
             }
+            Message::ShowPolicyPreview => {
+                if let Some(profile) = self.selected_profile.and_then(|i| self.profiles.get(i)) {
+                    self.policy_preview = Some(policy_preview_json(profile));
+                }
+            }
+            Message::CopyPolicyPreview => {
+                if let Some(preview) = &self.policy_preview {
+                    self.notification = Some(Notification::success("Copied policy preview"));
+                    return iced::clipboard::write(preview.clone());
+                }
+            }
+            Message::CopyProtoDebug => {
+                if let Some(profile) = self.selected_profile.and_then(|i| self.profiles.get(i)) {
+                    self.notification = Some(Notification::success(format!(
+                        "Copied proto debug dump for \"{}\"",
+                        profile.name
+                    )));
+                    return iced::clipboard::write(policy_proto_debug(profile));
+                }
+            }
+            Message::ClosePolicyPreview => {
+                self.policy_preview = None;
+            }
+            Message::ProfileExported(result) => match result {
+                Ok(Some(path)) => {
+                    self.notification =
+                        Some(Notification::success(format!("Exported to {}", path)));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.notification = Some(Notification::error(format!("Export failed: {}", e)));
+                }
+            },
+            Message::DismissNotification => {
+                self.notification = None;
+            }
+            Message::NotificationExpired(generation) => {
+                let transient = self.notification.as_ref().is_some_and(|n| !n.is_error);
+                if generation == self.notification_generation && transient {
+                    self.notification = None;
+                }
+            }
+            Message::ResolveUnsavedChanges(choice) => {
+                let Some(navigation) = self.pending_navigation.take() else {
+                    return Task::none();
+                };
+                match choice {
+                    UnsavedChangesChoice::Save => {
+                        if self.saving.is_some() {
+                            self.pending_navigation = Some(navigation);
+                            return Task::none();
+                        }
+                        self.navigation_after_save = Some(navigation);
+                        let task = self.update(Message::SaveProfile);
+                        if self.saving.is_none() {
+                            self.navigation_after_save = None;
+                        }
+                        return task;
+                    }
+                    UnsavedChangesChoice::Discard => {
+                        self.forget_last_deleted();
+                        self.discard_selected_profile_changes();
+                    }
+                    UnsavedChangesChoice::Cancel => return Task::none(),
+                }
+                return self.navigate(navigation);
+            }
             Message::SwitchView(mode) => {
+                if self.has_unsaved_changes() {
+                    self.pending_navigation = Some(PendingNavigation::SwitchView(mode));
+                    return Task::none();
+                }
                 self.view_mode = mode;
                 if mode == ViewMode::ProfileList {
                     self.selected_profile = None;
-                } else if mode == ViewMode::RunHistory && self.grpc_client.is_some() {
-                    self.loading_state = LoadingState::LoadingHistory;
-                    let mut client = self.grpc_client.take().unwrap();
+                } else if mode == ViewMode::Settings {
+                    self.connection_inputs = ConnectionInputs {
+                        mode: self.settings.connection_mode,
+                        socket_path: self.settings.socket_path.clone().unwrap_or_default(),
+                        tcp_address: self.settings.tcp_address.clone().unwrap_or_default(),
+                    };
+                } else if mode == ViewMode::RunHistory {
+                    return self.load_history();
+                }
+            }
+            Message::HistoryFilterChanged(filter) => {
+                self.history_filter.input = filter;
+                self.history_filter_generation += 1;
+                let generation = self.history_filter_generation;
+                return Task::perform(tokio::time::sleep(HISTORY_FILTER_DEBOUNCE), move |_| {
+                    Message::HistoryFilterDebounced(generation)
+                });
+            }
+            Message::HistoryFilterDebounced(generation) => {
+                if generation == self.history_filter_generation {
+                    self.history_filter.query = self.history_filter.input.clone();
+                    self.history_page = 0;
+                }
+            }
+            Message::WindowResized(size) => {
+                let geometry = self.window_geometry();
+                geometry.width = size.width;
+                geometry.height = size.height;
+                return self.schedule_window_geometry_save();
+            }
+            Message::WindowMoved(position) => {
+                let geometry = self.window_geometry();
+                geometry.x = Some(position.x);
+                geometry.y = Some(position.y);
+                return self.schedule_window_geometry_save();
+            }
+            Message::SaveWindowGeometry(generation) => {
+                if generation == self.window_geometry_generation {
+                    self.persist_settings();
+                }
+            }
+            Message::HistorySortChanged(order) => {
+                self.history_sort = order;
+                self.history_page = 0;
+            }
+            Message::HistoryDateFromChanged(date) => {
+                self.history_filter.from = date;
+                self.history_page = 0;
+            }
+            Message::HistoryDateToChanged(date) => {
+                self.history_filter.to = date;
+                self.history_page = 0;
+            }
+            Message::HistoryPageChanged(page) => {
+                self.history_page = page;
+            }
+            Message::HistoryAutoRefreshToggled(enabled) => {
+                self.settings.history_auto_refresh = enabled;
+                self.persist_settings();
+            }
+            Message::HistoryRefreshIntervalChanged(value) => {
+                let value = value.trim();
+                if value.is_empty() {
+                    self.settings.history_refresh_secs = None;
+                } else if let Ok(secs @ 1..) = value.parse::<u64>() {
+                    self.settings.history_refresh_secs = Some(secs);
+                } else {
+                    return Task::none();
+                }
+                self.persist_settings();
+            }
+            Message::DefaultRunCommandChanged(command) => {
+                self.settings.default_run_command = (!command.trim().is_empty()).then_some(command);
+                self.persist_settings();
+            }
+            Message::RefreshHistory => {
+                if self.view_mode == ViewMode::RunHistory
+                    && self.loading_state == LoadingState::Idle
+                {
+                    return self.load_history();
+                }
+            }
+            Message::RefreshStats => {
+                let running: Vec<String> = self
+                    .run_history
+                    .iter()
+                    .filter(|r| r.is_running())
+                    .map(|r| r.id.clone())
+                    .collect();
+                if running.is_empty() || self.loading_state != LoadingState::Idle {
+                    return Task::none();
+                }
+                if let Some(mut client) = self.grpc_client.take() {
                     return Task::perform(
                         async move {
-                            let result = client.list_sandboxes(true).await;
-                            (client, result)
-                        },
-                        move |(client, result)| match result {
-                            Ok(sandboxes) => {
-                                let records: Vec<RunRecord> = sandboxes
-                                    .into_iter()
-                                    .map(|s| RunRecord {
-                                        id: s.sandbox_id.clone(),
-                                        profile_name: "unknown".to_string(),
-                                        start_time: format_timestamp(0),
-                                        duration: "unknown".to_string(),
-                                        exit_code: 0,
-                                        denied_capabilities: vec![],
-                                    })
-                                    .collect();
-                                Message::HistoryLoaded(Ok(records), client)
+                            let mut results = Vec::with_capacity(running.len());
+                            for sandbox_id in running {
+                                let usage = client
+                                    .get_status(sandbox_id.clone())
+                                    .await
+                                    .map(|status| status.resource_usage.unwrap_or_default())
+                                    .map_err(|e| e.to_string());
+                                results.push((sandbox_id, usage));
                             }
-                            Err(e) => Message::HistoryLoaded(Err(e.to_string()), client),
+                            (client, results)
                         },
+                        |(client, results)| Message::StatsUpdated(results, client),
                     );
                 }
             }
-            Message::HistoryFilterChanged(filter) => {
-                self.history_filter = filter;
+            Message::StatsUpdated(results, client) => {
+                self.restore_client(client);
+                for (sandbox_id, usage) in results {
+                    let Ok(usage) = usage else {
+                        continue;
+                    };
+                    let samples = self.sandbox_stats.entry(sandbox_id).or_default();
+                    samples.push(usage);
+                    if samples.len() > STATS_HISTORY_LEN {
+                        samples.remove(0);
+                    }
+                }
+            }
+            Message::ProfileFilterChanged(filter) => {
+                self.profile_filter = filter;
             }
-            Message::GrpcClientConnected(result) => match result {
-                Ok(client) => {
-                    self.grpc_client = Some(client);
+            Message::ProfileNetworkFilterChanged(filter) => {
+                self.profile_network_filter = filter;
+            }
+            Message::ThemeChanged(theme) => {
+                self.settings.theme = theme;
+                self.persist_settings();
+            }
+            Message::ProfileSortChanged(order) => {
+                self.settings.profile_sort = order;
+                self.persist_settings();
+            }
+            Message::ProfileGroupingChanged(grouping) => {
+                self.settings.profile_group_by = grouping.key().map(str::to_string);
+                self.persist_settings();
+            }
+            Message::GrpcClientConnected(generation, _)
+                if generation != self.connection_generation => {}
+            Message::GrpcClientConnected(_, result) => match result {
+                Ok(mut client) => {
                     self.daemon_status = DaemonStatus::Connected;
+                    self.connection_error = None;
+                    return Task::perform(
+                        async move {
+                            let mismatch = client.check_daemon_version().await.unwrap_or(None);
+                            (client, mismatch)
+                        },
+                        |(client, mismatch)| Message::DaemonVersionChecked(mismatch, client),
+                    );
                 }
-                Err(_) => {
+                Err(e) => {
                     self.daemon_status = DaemonStatus::Offline;
+                    self.connection_error = Some(e);
                 }
             },
+            Message::DaemonVersionChecked(mismatch, client) => {
+                self.restore_client(client);
+                self.version_mismatch = mismatch;
+                if self.view_mode == ViewMode::RunHistory {
+                    return self.load_history();
+                }
+            }
+            Message::ConnectionModeChanged(mode) => {
+                self.connection_inputs.mode = mode;
+            }
+            Message::SocketPathInputChanged(value) => {
+                self.connection_inputs.socket_path = value;
+            }
+            Message::TcpAddressInputChanged(value) => {
+                self.connection_inputs.tcp_address = value;
+            }
+            Message::ApplyConnectionSettings => {
+                let tcp_address = self.connection_inputs.tcp_address.trim();
+                if self.connection_inputs.mode == ConnectionMode::Tcp {
+                    if let Err(e) = GrpcClient::validate_tcp_address(tcp_address) {
+                        self.connection_error = Some(e.to_string());
+                        return Task::none();
+                    }
+                }
+                let socket_path = self.connection_inputs.socket_path.trim();
+                self.settings.connection_mode = self.connection_inputs.mode;
+                self.settings.socket_path = if socket_path.is_empty() {
+                    None
+                } else {
+                    Some(socket_path.to_string())
+                };
+                self.settings.tcp_address = if tcp_address.is_empty() {
+                    None
+                } else {
+                    Some(tcp_address.to_string())
+                };
+                self.persist_settings();
+                self.grpc_client = None;
+                self.connection_generation += 1;
+                self.daemon_status = DaemonStatus::Unknown;
+                self.connection_error = None;
+                self.version_mismatch = None;
+                return self.connect();
+            }
+            Message::ReconnectDaemon => {
+                self.grpc_client = None;
+                self.connection_generation += 1;
+                self.daemon_status = DaemonStatus::Unknown;
+                self.connection_error = None;
+                return self.connect();
+            }
+            Message::RunCommandChanged(command) => {
+                self.run_command_input = command;
+                self.run_command_error = None;
+            }
             Message::RunSandbox {
                 profile_idx,
                 command,
             } => {
+                if self.loading_state == LoadingState::RunningSandbox {
+                    return Task::none();
+                }
                 if let Some(profile) = self.profiles.get(profile_idx) {
+                    let cmd_parts: Vec<String> =
+                        command.split_whitespace().map(|s| s.to_string()).collect();
+                    if cmd_parts.is_empty() {
+                        self.run_command_error = Some("Enter a command to run".to_string());
+                        return Task::none();
+                    }
+                    if let Some(issue) = profile
+                        .validate(self.settings.connection_mode)
+                        .into_iter()
+                        .find(|i| i.is_error())
+                    {
+                        self.notification = Some(Notification::error(format!(
+                            "Cannot run \"{}\": {}",
+                            profile.name, issue.message
+                        )));
+                        return Task::none();
+                    }
                     if let Some(mut client) = self.grpc_client.take() {
-                        self.loading_state = LoadingState::RunningSandbox;
                         let policy = profile.clone();
-                        let cmd_parts: Vec<String> =
-                            command.split_whitespace().map(|s| s.to_string()).collect();
+                        self.settings.remember_command(&policy.name, command.trim());
+                        self.persist_settings();
+                        self.loading_state = LoadingState::RunningSandbox;
+                        let launch = SandboxLaunch {
+                            profile: policy.name.clone(),
+                            command: command.trim().to_string(),
+                        };
+                        let working_dir = policy.sandbox.working_directory().map(str::to_string);
                         return Task::perform(
                             async move {
                                 let result = client
-                                    .run_sandbox(&policy, cmd_parts, Some("/".to_string()))
+                                    .run_sandbox(&policy, cmd_parts, working_dir.clone())
                                     .await;
-                                (client, result)
+                                (client, result, launch)
                             },
-                            |(client, result)| {
-                                Message::RunSandboxResult(
-                                    result.map(|r| r.sandbox_id).map_err(|e| e.to_string()),
-                                    client,
-                                )
+                            |(client, result, launch)| {
+                                let result = match result {
+                                    Ok(response) if response.success => Ok(response.sandbox_id),
+                                    Ok(response) => Err(response.error.unwrap_or_else(|| {
+                                        "Daemon refused to start sandbox".into()
+                                    })),
+                                    Err(e) => Err(e.to_string()),
+                                };
+                                Message::RunSandboxResult(result, launch, client)
                             },
                         );
                     }
+                    self.notification = Some(Notification::error(
+                        if self.daemon_status == DaemonStatus::Connected {
+                            "Cannot run yet: the daemon is still answering another request. Try again in a moment"
+                        } else {
+                            "Not connected to daemon. Start hopsd or check the connection in Settings, then reconnect"
+                        }
+                        .to_string(),
+                    ));
                 }
             }
-            Message::RunSandboxResult(result, client) => {
-                self.grpc_client = Some(client);
+            Message::RunSandboxResult(result, launch, client) => {
+                self.restore_client(client);
                 self.loading_state = LoadingState::Idle;
                 match result {
-                    Ok(_sandbox_id) => {}
-                    Err(_) => {}
+                    Ok(sandbox_id) => {
+                        self.notification = Some(Notification::success(format!(
+                            "Started sandbox {}",
+                            sandbox_id
+                        )));
+                        self.settings.sandbox_launches.insert(sandbox_id, launch);
+                        self.persist_settings();
+                        if self.view_mode == ViewMode::RunHistory {
+                            return self.load_history();
+                        }
+                    }
+                    Err(e) => {
+                        self.notification = Some(Notification::error(format!("Run failed: {}", e)));
+                    }
                 }
             }
-            Message::StopSandbox { sandbox_id } => {
+            Message::RequestStopSandbox(sandbox_id) => {
+                self.pending_stop = Some(PendingStop {
+                    sandbox_id,
+                    force: false,
+                });
+            }
+            Message::StopForceToggled(force) => {
+                if let Some(pending) = &mut self.pending_stop {
+                    pending.force = force;
+                }
+            }
+            Message::CancelStopSandbox => {
+                self.pending_stop = None;
+            }
+            Message::StopSandbox { sandbox_id, force } => {
+                self.pending_stop = None;
                 if let Some(mut client) = self.grpc_client.take() {
                     return Task::perform(
                         async move {
-                            let result = client.stop_sandbox(sandbox_id, false).await;
+                            let result = match client.stop_sandbox(sandbox_id.clone(), force).await
+                            {
+                                Ok(response) if response.success => Ok(sandbox_id),
+                                Ok(response) => Err(response
+                                    .error
+                                    .unwrap_or_else(|| "Daemon refused to stop sandbox".into())),
+                                Err(e) => Err(e.to_string()),
+                            };
                             (client, result)
                         },
-                        |(client, result)| {
-                            Message::StopSandboxResult(
-                                result.map(|_| ()).map_err(|e| e.to_string()),
-                                client,
-                            )
-                        },
+                        |(client, result)| Message::StopSandboxResult(result, client),
                     );
                 }
             }
+            Message::CopySandboxId(sandbox_id) => {
+                self.notification = Some(Notification::success(format!(
+                    "Copied sandbox id {}",
+                    sandbox_id
+                )));
+                self.notification_generation += 1;
+                let generation = self.notification_generation;
+                return Task::batch([
+                    iced::clipboard::write(sandbox_id),
+                    Task::perform(tokio::time::sleep(COPIED_TOAST_DURATION), move |_| {
+                        Message::NotificationExpired(generation)
+                    }),
+                ]);
+            }
+            Message::RerunSandbox(sandbox_id) => {
+                let Some(record) = self.run_history.iter().find(|r| r.id == sandbox_id) else {
+                    return Task::none();
+                };
+                let Some(profile_idx) = self
+                    .profiles
+                    .iter()
+                    .position(|p| p.name == record.profile_name)
+                else {
+                    self.notification = Some(Notification::error(format!(
+                        "Profile \"{}\" no longer exists",
+                        record.profile_name
+                    )));
+                    return Task::none();
+                };
+                let command = record.command.clone();
+                if command.trim().is_empty() {
+                    self.notification = Some(Notification::error(format!(
+                        "No command was recorded for sandbox {}",
+                        sandbox_id
+                    )));
+                    return Task::none();
+                }
+                return self.update(Message::RunSandbox {
+                    profile_idx,
+                    command,
+                });
+            }
+            Message::RequestStopAll => {
+                if self.stop_all.is_none() && self.run_history.iter().any(RunRecord::is_running) {
+                    self.pending_stop_all = true;
+                }
+            }
+            Message::CancelStopAll => {
+                self.pending_stop_all = false;
+            }
+            Message::StopAll => {
+                self.pending_stop_all = false;
+                let queue: Vec<String> = self
+                    .run_history
+                    .iter()
+                    .filter(|r| r.is_running())
+                    .map(|r| r.id.clone())
+                    .rev()
+                    .collect();
+                self.stop_all = Some(StopAllProgress {
+                    total: queue.len(),
+                    queue,
+                    ..Default::default()
+                });
+                return self.stop_next_queued();
+            }
             Message::StopSandboxResult(result, client) => {
-                self.grpc_client = Some(client);
+                self.restore_client(client);
+                if let Ok(sandbox_id) = &result {
+                    if let Some(record) = self.run_history.iter_mut().find(|r| &r.id == sandbox_id)
+                    {
+                        record.state = SandboxState::Stopped;
+                    }
+                }
+                if let Some(progress) = &mut self.stop_all {
+                    if result.is_ok() {
+                        progress.stopped += 1;
+                    } else {
+                        progress.failed += 1;
+                    }
+                    return self.stop_next_queued();
+                }
                 match result {
-                    Ok(_) => {}
-                    Err(_) => {}
+                    Ok(sandbox_id) => {
+                        self.notification = Some(Notification::success(format!(
+                            "Stopped sandbox {}",
+                            sandbox_id
+                        )));
+                        self.notification_generation += 1;
+                        let generation = self.notification_generation;
+                        return Task::batch([
+                            self.load_history(),
+                            Task::perform(tokio::time::sleep(STOPPED_TOAST_DURATION), move |_| {
+                                Message::NotificationExpired(generation)
+                            }),
+                        ]);
+                    }
+                    Err(e) => {
+                        self.notification =
+                            Some(Notification::error(format!("Stop failed: {}", e)));
+                    }
+                }
+            }
+            Message::ShowSandboxDetails(sandbox_id) => {
+                let Some(mut client) = self.grpc_client.take() else {
+                    self.sandbox_details = Some(SandboxDetails {
+                        sandbox_id,
+                        status: Some(Err("Not connected to daemon".to_string())),
+                    });
+                    return Task::none();
+                };
+                self.sandbox_details = Some(SandboxDetails {
+                    sandbox_id: sandbox_id.clone(),
+                    status: None,
+                });
+                return Task::perform(
+                    async move {
+                        let result = client.get_status(sandbox_id).await;
+                        (client, result)
+                    },
+                    |(client, result)| {
+                        Message::StatusLoaded(result.map_err(|e| e.to_string()), client)
+                    },
+                );
+            }
+            Message::StatusLoaded(result, client) => {
+                self.restore_client(client);
+                if let Some(details) = self.sandbox_details.as_mut() {
+                    if let Ok(status) = &result {
+                        if let Some(record) = self
+                            .run_history
+                            .iter_mut()
+                            .find(|r| r.id == details.sandbox_id)
+                        {
+                            record.denied_capabilities = status.denied_capabilities.clone();
+                            if status.start_time != 0 {
+                                record.start_time = format_timestamp(status.start_time);
+                                record.started_at = status.start_time;
+                            }
+                            if let Some(exit_code) = status.exit_code {
+                                record.exit_code = exit_code;
+                            }
+                        }
+                    }
+                    details.status = Some(result);
                 }
             }
+            Message::CloseSandboxDetails => {
+                self.sandbox_details = None;
+            }
+            Message::ExportHistoryCsv => {
+                let csv = history::records_to_csv(&self.run_history);
+                return Task::perform(
+                    async move {
+                        let Some(handle) = rfd::AsyncFileDialog::new()
+                            .set_file_name("run-history.csv")
+                            .add_filter("CSV", &["csv"])
+                            .save_file()
+                            .await
+                        else {
+                            return Ok(None);
+                        };
+                        let path = handle.path().to_path_buf();
+                        tokio::fs::write(&path, csv)
+                            .await
+                            .map(|_| Some(path.display().to_string()))
+                            .map_err(|e| e.to_string())
+                    },
+                    Message::HistoryExported,
+                );
+            }
+            Message::RequestClearHistory => {
+                self.pending_clear_history = true;
+            }
+            Message::CancelClearHistory => {
+                self.pending_clear_history = false;
+            }
+            Message::ClearHistory => {
+                self.pending_clear_history = false;
+                let (running, finished): (Vec<RunRecord>, Vec<RunRecord>) =
+                    std::mem::take(&mut self.run_history)
+                        .into_iter()
+                        .partition(RunRecord::is_running);
+                self.run_history = running;
+                self.history_page = 0;
+                self.settings
+                    .hidden_sandbox_ids
+                    .extend(finished.iter().map(|r| r.id.clone()));
+                self.notification = Some(Notification::success(format!(
+                    "Cleared {} finished run{}",
+                    finished.len(),
+                    if finished.len() == 1 { "" } else { "s" }
+                )));
+                self.persist_settings();
+            }
+            Message::HistoryExported(result) => match result {
+                Ok(Some(path)) => {
+                    self.notification = Some(Notification::success(format!(
+                        "Exported {} runs to {}",
+                        self.run_history.len(),
+                        path
+                    )));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.notification = Some(Notification::error(format!("Export failed: {}", e)));
+                }
+            },
             Message::HistoryLoaded(result, client) => {
-                self.grpc_client = Some(client);
+                self.restore_client(client);
                 self.loading_state = LoadingState::Idle;
                 match result {
                     Ok(history) => {
-                        self.run_history = history;
+                        let hidden = &mut self.settings.hidden_sandbox_ids;
+                        let launches = &mut self.settings.sandbox_launches;
+                        let stored_count = hidden.len() + launches.len();
+                        hidden.retain(|id| history.iter().any(|r| &r.id == id));
+                        launches.retain(|id, _| history.iter().any(|r| &r.id == id));
+                        if hidden.len() + launches.len() != stored_count {
+                            self.persist_settings();
+                        }
+                        self.run_history = history
+                            .into_iter()
+                            .filter(|r| !self.settings.hidden_sandbox_ids.contains(&r.id))
+                            .map(|mut r| {
+                                if let Some(launch) = self.settings.sandbox_launches.get(&r.id) {
+                                    r.profile_name = launch.profile.clone();
+                                    r.command = launch.command.clone();
+                                }
+                                r
+                            })
+                            .collect();
+                        let run_history = &self.run_history;
+                        self.sandbox_stats.retain(|id, _| {
+                            run_history.iter().any(|r| &r.id == id && r.is_running())
+                        });
+                    }
+                    Err(e) => {
+                        self.daemon_status = DaemonStatus::Offline;
+                        self.connection_error = Some(format!(
+                            "Could not load run history: {}. Check that hopsd is running, then reconnect",
+                            e
+                        ));
                     }
-                    Err(_) => {}
                 }
             }
         }
         Task::none()
     }
 
+    fn load_history(&mut self) -> Task<Message> {
+        let Some(mut client) = self.grpc_client.take() else {
+            return Task::none();
+        };
+        self.loading_state = LoadingState::LoadingHistory;
+        Task::perform(
+            async move {
+                let result = client.list_sandboxes(true).await;
+                (client, result)
+            },
+            move |(client, result)| match result {
+                Ok(sandboxes) => {
+                    let records: Vec<RunRecord> = sandboxes
+                        .into_iter()
+                        .map(|s| RunRecord {
+                            id: s.sandbox_id.clone(),
+                            profile_name: "unknown".to_string(),
+                            command: String::new(),
+                            start_time: format_timestamp(s.start_time),
+                            started_at: s.start_time,
+                            duration: "unknown".to_string(),
+                            exit_code: s.exit_code.unwrap_or(0),
+                            pid: s.pid,
+                            state: s.state(),
+                            denied_capabilities: s.denied_capabilities,
+                        })
+                        .collect();
+                    Message::HistoryLoaded(Ok(records), client)
+                }
+                Err(e) => Message::HistoryLoaded(Err(e.to_string()), client),
+            },
+        )
+    }
+
+    fn open_new_profile(&mut self, policy: Policy) {
+        self.memory_display_value = policy
+            .capabilities
+            .resource_limits
+            .memory_bytes
+            .map(|bytes| format_memory_value(bytes, self.memory_unit))
+            .unwrap_or_default();
+        self.profiles.push(policy);
+        self.selected_profile = Some(self.profiles.len() - 1);
+        self.dirty = true;
+        self.editor_snapshot = None;
+        self.view_mode = ViewMode::ProfileEditor;
+        self.path_inputs = PathInputs::default();
+        self.validation_errors = ValidationErrors::default();
+    }
+
+    fn duplicate_of(&self, index: usize) -> Option<Policy> {
+        let mut profile = self.profiles.get(index)?.clone();
+        profile.name = self.unique_name_with_suffix(&format!("{}-copy", profile.name), 2);
+        Some(profile)
+    }
+
+    fn unique_profile_name(&self, base: &str) -> String {
+        self.unique_name_with_suffix(base, 1)
+    }
+
+    fn forget_last_deleted(&mut self) {
+        self.last_deleted = None;
+        self.undo_generation += 1;
+    }
+
+    fn unique_name_with_suffix(&self, base: &str, first_suffix: usize) -> String {
+        let is_taken = |name: &str| self.profiles.iter().any(|p| p.name == name);
+        if !is_taken(base) {
+            return base.to_string();
+        }
+        (first_suffix..)
+            .map(|n| format!("{}-{}", base, n))
+            .find(|name| !is_taken(name))
+            .unwrap_or_else(|| base.to_string())
+    }
+
+    fn add_paths(&mut self, path_type: PathType) {
+        let field_name = format!("{:?}_path", path_type);
+        let input = match path_type {
+            PathType::Allowed => std::mem::take(&mut self.path_inputs.allowed_input),
+            PathType::Denied => std::mem::take(&mut self.path_inputs.denied_input),
+        };
+        let candidates: Vec<&str> = input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        if candidates.is_empty() {
+            self.validation_errors
+                .fields
+                .insert(field_name, "Path cannot be empty".to_string());
+            return;
+        }
+
+        let Some(profile) = self
+            .selected_profile
+            .and_then(|idx| self.profiles.get_mut(idx))
+        else {
+            return;
+        };
+        let paths = match path_type {
+            PathType::Allowed => &mut profile.capabilities.allowed_paths,
+            PathType::Denied => &mut profile.capabilities.denied_paths,
+        };
+
+        let mut added = 0;
+        let mut rejected = Vec::new();
+        let mut remaining = Vec::new();
+        for candidate in &candidates {
+            if !candidate.starts_with('/') {
+                rejected.push(format!("{} (not absolute)", candidate));
+                remaining.push(*candidate);
+            } else if paths.iter().any(|entry| entry.path == *candidate) {
+                rejected.push(format!("{} (duplicate)", candidate));
+            } else {
+                paths.push(PathEntry::new(*candidate));
+                added += 1;
+            }
+        }
+
+        if added > 0 {
+            self.dirty = true;
+        }
+
+        let remaining = remaining.join("\n");
+        match path_type {
+            PathType::Allowed => self.path_inputs.allowed_input = remaining,
+            PathType::Denied => self.path_inputs.denied_input = remaining,
+        }
+
+        self.validation_errors.fields.remove(&field_name);
+        if !rejected.is_empty() {
+            self.notification = Some(Notification::error(format!(
+                "Added {} path{}, rejected {}",
+                added,
+                if added == 1 { "" } else { "s" },
+                rejected.join(", ")
+            )));
+        } else if candidates.len() > 1 {
+            self.notification = Some(Notification::success(format!("Added {} paths", added)));
+        }
+    }
+
+    fn move_path_to_other_list(&mut self, path_type: PathType, index: usize) {
+        let Some(profile) = self
+            .selected_profile
+            .and_then(|idx| self.profiles.get_mut(idx))
+        else {
+            return;
+        };
+        let root_path = profile.sandbox.root_path.clone();
+        let capabilities = &mut profile.capabilities;
+        let (source, destination) = match path_type {
+            PathType::Allowed => (
+                &mut capabilities.allowed_paths,
+                &mut capabilities.denied_paths,
+            ),
+            PathType::Denied => (
+                &mut capabilities.denied_paths,
+                &mut capabilities.allowed_paths,
+            ),
+        };
+        let Some(path) = source.get(index).cloned() else {
+            return;
+        };
+
+        let field_name = format!("{:?}_path", path_type.opposite());
+        let rejection = if destination.iter().any(|entry| entry.path == path.path) {
+            Some("duplicate".to_string())
+        } else if path_type == PathType::Allowed && Path::new(&root_path).starts_with(&path.path) {
+            Some(format!("contains the root path {}", root_path))
+        } else if path_type == PathType::Denied {
+            source
+                .iter()
+                .find(|denied| {
+                    denied.path != path.path && Path::new(&path.path).starts_with(&denied.path)
+                })
+                .map(|denied| format!("inside denied path {}", denied))
+        } else {
+            None
+        };
+        if let Some(reason) = rejection {
+            self.validation_errors
+                .fields
+                .insert(field_name, format!("Rejected: {} ({})", path, reason));
+            return;
+        }
+
+        source.remove(index);
+        destination.push(path);
+        capabilities.prune_path_capabilities();
+        self.validation_errors.fields.remove(&field_name);
+        self.dirty = true;
+    }
+
+    fn add_outbound_entries(&mut self) {
+        let field_name = "outbound_allowlist".to_string();
+        let input = std::mem::take(&mut self.path_inputs.outbound_input);
+        let candidates: Vec<&str> = input
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|entry| !entry.is_empty())
+            .collect();
+
+        if candidates.is_empty() {
+            self.validation_errors
+                .fields
+                .insert(field_name, "Entry cannot be empty".to_string());
+            return;
+        }
+
+        let Some(profile) = self
+            .selected_profile
+            .and_then(|idx| self.profiles.get_mut(idx))
+        else {
+            return;
+        };
+        let allowlist = &mut profile.capabilities.outbound_allowlist;
+
+        let mut rejected = Vec::new();
+        let mut remaining = Vec::new();
+        for candidate in candidates {
+            if let Err(e) = capability::validate_outbound_entry(candidate) {
+                rejected.push(e);
+                remaining.push(candidate);
+            } else if allowlist.iter().any(|entry| entry == candidate) {
+                rejected.push(format!("{} (duplicate)", candidate));
+            } else {
+                allowlist.push(candidate.to_string());
+                self.dirty = true;
+            }
+        }
+        self.path_inputs.outbound_input = remaining.join(" ");
+
+        if rejected.is_empty() {
+            self.validation_errors.fields.remove(&field_name);
+        } else {
+            self.validation_errors
+                .fields
+                .insert(field_name, format!("Rejected: {}", rejected.join(", ")));
+        }
+    }
+
+    fn merge_loaded_profiles(&mut self, mut loaded: Vec<Policy>) {
+        let name_at = |index: Option<usize>| {
+            index
+                .and_then(|idx| self.profiles.get(idx))
+                .map(|p| p.name.clone())
+        };
+        let highlighted_name = name_at(self.highlighted_profile);
+        let pending_delete_name = name_at(self.pending_delete);
+        let selected_name = name_at(self.selected_profile);
+
+        let editing = if self.view_mode == ViewMode::ProfileEditor {
+            self.selected_profile
+                .and_then(|idx| self.profiles.get(idx))
+                .cloned()
+        } else {
+            None
+        };
+
+        if let Some(current) = editing {
+            let on_disk = self
+                .editor_snapshot
+                .as_ref()
+                .and_then(|snapshot| loaded.iter().position(|p| p.name == snapshot.name));
+            let index = match on_disk {
+                Some(pos) if !self.dirty => {
+                    let external = loaded[pos].clone();
+                    self.memory_display_value = external
+                        .capabilities
+                        .resource_limits
+                        .memory_bytes
+                        .map(|bytes| format_memory_value(bytes, self.memory_unit))
+                        .unwrap_or_default();
+                    self.editor_snapshot = Some(external);
+                    pos
+                }
+                Some(pos) => {
+                    let external = std::mem::replace(&mut loaded[pos], current);
+                    let unchanged = self.editor_snapshot.as_ref().is_some_and(|snapshot| {
+                        config::profile_to_toml(snapshot).ok()
+                            == config::profile_to_toml(&external).ok()
+                    });
+                    if !unchanged {
+                        self.external_change = Some(external);
+                    }
+                    pos
+                }
+                None => {
+                    if self.editor_snapshot.take().is_some() {
+                        self.dirty = true;
+                        self.notification = Some(Notification::error(format!(
+                            "\"{}\" was deleted on disk. Save to keep it.",
+                            current.name
+                        )));
+                    }
+                    loaded.push(current);
+                    loaded.len() - 1
+                }
+            };
+            self.selected_profile = Some(index);
+        } else {
+            self.selected_profile =
+                selected_name.and_then(|name| loaded.iter().position(|p| p.name == name));
+        }
+
+        self.highlighted_profile =
+            highlighted_name.and_then(|name| loaded.iter().position(|p| p.name == name));
+        self.pending_delete =
+            pending_delete_name.and_then(|name| loaded.iter().position(|p| p.name == name));
+        self.profiles = loaded;
+    }
+
+    fn window_geometry(&mut self) -> &mut WindowGeometry {
+        self.settings.window.get_or_insert_with(|| {
+            let size = iced::window::Settings::default().size;
+            WindowGeometry {
+                width: size.width,
+                height: size.height,
+                x: None,
+                y: None,
+            }
+        })
+    }
+
+    fn schedule_window_geometry_save(&mut self) -> Task<Message> {
+        self.window_geometry_generation += 1;
+        let generation = self.window_geometry_generation;
+        Task::perform(
+            tokio::time::sleep(WINDOW_GEOMETRY_SAVE_DEBOUNCE),
+            move |_| Message::SaveWindowGeometry(generation),
+        )
+    }
+
+    fn stop_next_queued(&mut self) -> Task<Message> {
+        let Some(progress) = &mut self.stop_all else {
+            return Task::none();
+        };
+        if self.grpc_client.is_some() {
+            if let Some(sandbox_id) = progress.queue.pop() {
+                return self.update(Message::StopSandbox {
+                    sandbox_id,
+                    force: false,
+                });
+            }
+        }
+        let remaining = progress.queue.len();
+        let StopAllProgress {
+            total,
+            stopped,
+            failed,
+            ..
+        } = *progress;
+        self.stop_all = None;
+        self.notification = Some(if stopped == total {
+            Notification::success(format!(
+                "Stopped {} sandbox{}",
+                stopped,
+                if stopped == 1 { "" } else { "es" }
+            ))
+        } else {
+            Notification::error(format!(
+                "Stopped {} of {} sandboxes ({} failed, {} skipped)",
+                stopped, total, failed, remaining
+            ))
+        });
+        Task::none()
+    }
+
+    fn has_unsaved_changes(&self) -> bool {
+        (self.dirty || self.is_unsaved_new_profile()) && self.view_mode == ViewMode::ProfileEditor
+    }
+
+    fn is_unsaved_new_profile(&self) -> bool {
+        self.selected_profile.is_some() && self.editor_snapshot.is_none()
+    }
+
+    fn profile_to_save(&mut self) -> Option<Policy> {
+        if !self.validation_errors.fields.is_empty() {
+            return None;
+        }
+        let idx = self.selected_profile?;
+        let profile = self.profiles.get(idx)?;
+        let name_taken = self
+            .profiles
+            .iter()
+            .enumerate()
+            .any(|(i, p)| i != idx && p.name == profile.name);
+        if name_taken {
+            let error = format!("Another profile is already named \"{}\"", profile.name);
+            self.validation_errors
+                .fields
+                .insert("name".to_string(), error);
+            return None;
+        }
+        Some(profile.clone())
+    }
+
+    fn navigate(&mut self, navigation: PendingNavigation) -> Task<Message> {
+        match navigation {
+            PendingNavigation::SwitchView(mode) => self.update(Message::SwitchView(mode)),
+            PendingNavigation::CreateNewProfile => self.update(Message::CreateNewProfile),
+            PendingNavigation::DuplicateSelectedProfile => {
+                self.update(Message::DuplicateSelectedProfile)
+            }
+        }
+    }
+
+    pub fn bulk_edit_targets(&self, edit: &BulkEdit) -> Vec<(usize, Policy)> {
+        let candidates: Vec<usize> = if edit.filtered_only {
+            profile_list::visible_profiles(
+                &self.profiles,
+                &self.profile_filter,
+                self.profile_network_filter,
+                self.settings.profile_sort,
+                self.settings.profile_group_by.as_deref(),
+            )
+            .into_iter()
+            .map(|(idx, _)| idx)
+            .collect()
+        } else {
+            (0..self.profiles.len()).collect()
+        };
+        candidates
+            .into_iter()
+            .filter_map(|idx| {
+                let mut updated = self.profiles[idx].clone();
+                edit.change.apply(&mut updated);
+                (!self.profiles[idx].diff(&updated).is_empty()).then_some((idx, updated))
+            })
+            .collect()
+    }
+
+    pub fn bulk_edit_conflict(&self, targets: &[(usize, Policy)]) -> Option<&str> {
+        let idx = self.selected_profile.filter(|_| self.dirty)?;
+        targets
+            .iter()
+            .any(|(target, _)| *target == idx)
+            .then(|| self.profiles[idx].name.as_str())
+    }
+
+    fn apply_bulk_edit(&mut self, edit: BulkEdit) {
+        let targets = self.bulk_edit_targets(&edit);
+        if let Some(name) = self.bulk_edit_conflict(&targets) {
+            self.notification = Some(Notification::error(format!(
+                "Save or discard your changes to \"{}\" before applying a bulk edit",
+                name
+            )));
+            self.bulk_edit = Some(edit);
+            return;
+        }
+
+        let mut updated = 0;
+        let mut failed = Vec::new();
+        for (idx, mut policy) in targets {
+            match config::save_profile(&policy.name, &policy) {
+                Ok(()) => {
+                    policy.modified = Some(SystemTime::now());
+                    if self.selected_profile == Some(idx) {
+                        self.editor_snapshot = Some(policy.clone());
+                    }
+                    self.profiles[idx] = policy;
+                    updated += 1;
+                }
+                Err(e) => failed.push(format!("{} ({})", policy.name, e)),
+            }
+        }
+
+        self.notification = Some(if failed.is_empty() {
+            Notification::success(format!(
+                "{}: updated {} profile{}",
+                edit.change,
+                updated,
+                if updated == 1 { "" } else { "s" }
+            ))
+        } else {
+            Notification::error(format!(
+                "{}: updated {}, failed to save {}",
+                edit.change,
+                updated,
+                failed.join(", ")
+            ))
+        });
+    }
+
+    fn finish_save(&mut self, mut saved: Policy) {
+        saved.modified = Some(SystemTime::now());
+        let Some(idx) = self.profiles.iter().position(|p| p.name == saved.name) else {
+            return;
+        };
+        let profile = &mut self.profiles[idx];
+        profile.modified = saved.modified;
+        if self.selected_profile == Some(idx) {
+            self.dirty =
+                config::profile_to_toml(profile).ok() != config::profile_to_toml(&saved).ok();
+            self.editor_snapshot = Some(saved);
+        }
+    }
+
+    fn discard_selected_profile_changes(&mut self) {
+        if let Some(idx) = self.selected_profile {
+            match self.editor_snapshot.take() {
+                Some(snapshot) => {
+                    if let Some(profile) = self.profiles.get_mut(idx) {
+                        *profile = snapshot;
+                    }
+                }
+                None => {
+                    if idx < self.profiles.len() {
+                        self.profiles.remove(idx);
+                    }
+                    self.selected_profile = None;
+                }
+            }
+        }
+        self.validation_errors = ValidationErrors::default();
+        self.dirty = false;
+    }
+
     pub fn view(&self) -> Element<'_, Message> {
         let sidebar = self.view_sidebar();
 
         let content = match self.view_mode {
-            ViewMode::ProfileList => profile_list::view(&self.profiles),
+            ViewMode::ProfileList => profile_list::view(
+                &self.profiles,
+                &self.profile_filter,
+                self.profile_network_filter,
+                self.settings.profile_sort,
+                self.settings.profile_group_by.as_deref(),
+                self.highlighted_profile,
+                self.last_deleted.as_ref(),
+            ),
             ViewMode::ProfileEditor => {
                 if let Some(idx) = self.selected_profile {
                     if let Some(profile) = self.profiles.get(idx) {
-                        profile_editor::view(
-                            profile,
-                            &self.path_inputs,
-                            &self.validation_errors,
-                            &self.memory_unit,
-                            &self.memory_display_value,
-                        )
+                        iced::widget::column![
+                            profile_editor::run_bar(
+                                idx,
+                                &self.run_command_input,
+                                self.run_command_error.as_deref(),
+                                self.loading_state == LoadingState::RunningSandbox,
+                                self.settings.recent_commands_for(&profile.name),
+                                &self.policy_issues,
+                            ),
+                            profile_editor::validation_summary(
+                                &self.validation_errors,
+                                self.validation_flash,
+                            ),
+                            profile_editor::view(
+                                profile,
+                                &self.path_inputs,
+                                &self.validation_errors,
+                                &self.memory_unit,
+                                &self.memory_display_value,
+                                self.host_cores,
+                                match (&self.saving, self.dirty) {
+                                    (Some(_), _) => SaveState::Saving,
+                                    (None, true) => SaveState::Dirty,
+                                    (None, false) => SaveState::Clean,
+                                },
+                                &self.collapsed,
+                            )
+                        ]
+                        .into()
                     } else {
-                        profile_list::view(&self.profiles)
+                        profile_list::view(
+                            &self.profiles,
+                            &self.profile_filter,
+                            self.profile_network_filter,
+                            self.settings.profile_sort,
+                            self.settings.profile_group_by.as_deref(),
+                            self.highlighted_profile,
+                            self.last_deleted.as_ref(),
+                        )
                     }
                 } else {
-                    profile_list::view(&self.profiles)
+                    profile_list::view(
+                        &self.profiles,
+                        &self.profile_filter,
+                        self.profile_network_filter,
+                        self.settings.profile_sort,
+                        self.settings.profile_group_by.as_deref(),
+                        self.highlighted_profile,
+                        self.last_deleted.as_ref(),
+                    )
                 }
             }
-            ViewMode::RunHistory => run_history::view(&self.run_history, &self.history_filter),
+            ViewMode::RunHistory => run_history::view(
+                &self.run_history,
+                &self.history_filter,
+                self.history_sort,
+                self.history_page,
+                self.settings.history_auto_refresh,
+                self.loading_state == LoadingState::LoadingHistory,
+                &self.sandbox_stats,
+                self.daemon_status,
+                self.connection_error.as_deref(),
+            ),
+            ViewMode::Compare => profile_compare::view(
+                &self.profiles,
+                self.compare_left.as_deref(),
+                self.compare_right.as_deref(),
+            ),
+            ViewMode::Settings => settings_view::view(
+                &self.settings,
+                &self.connection_inputs,
+                self.daemon_status,
+                self.connection_error.as_deref(),
+            ),
         };
 
-        row![sidebar, content]
+        let content: Element<'_, Message> = match &self.notification {
+            Some(notice) => iced::widget::column![notification::view(notice), content].into(),
+            None => content,
+        };
+
+        let content: Element<'_, Message> = match &self.version_mismatch {
+            Some(mismatch) => iced::widget::column![notification::banner(mismatch), content].into(),
+            None => content,
+        };
+
+        let content: Element<'_, Message> = if self.loading_state == LoadingState::RunningSandbox {
+            iced::widget::column![notification::progress("Starting sandbox…"), content].into()
+        } else if let Some(progress) = &self.stop_all {
+            iced::widget::column![
+                notification::progress(format!(
+                    "Stopping sandboxes… {}/{}",
+                    progress.stopped + progress.failed + 1,
+                    progress.total
+                )),
+                content
+            ]
+            .into()
+        } else {
+            content
+        };
+
+        let base: Element<'_, Message> = row![sidebar, content]
             .width(Length::Fill)
             .height(Length::Fill)
-            .into()
+            .into();
+
+        if self.pending_navigation.is_some() {
+            if let Some(profile) = self.selected_profile.and_then(|idx| self.profiles.get(idx)) {
+                return modal::view(
+                    base,
+                    profile_editor::unsaved_changes_confirmation(
+                        &profile.name,
+                        self.is_unsaved_new_profile(),
+                    ),
+                    Message::ResolveUnsavedChanges(UnsavedChangesChoice::Cancel),
+                );
+            }
+        }
+
+        if let Some(preview) = &self.policy_preview {
+            if self.view_mode == ViewMode::ProfileEditor {
+                return modal::view(
+                    base,
+                    profile_editor::policy_preview(preview),
+                    Message::ClosePolicyPreview,
+                );
+            }
+        }
+
+        if let Some(external) = &self.external_change {
+            if self.view_mode == ViewMode::ProfileEditor {
+                return modal::view(
+                    base,
+                    profile_editor::external_change_confirmation(&external.name),
+                    Message::ResolveExternalChange(ExternalChangeChoice::KeepMine),
+                );
+            }
+        }
+
+        if let Some(pending) = &self.pending_stop {
+            if self.view_mode == ViewMode::RunHistory {
+                return modal::view(
+                    base,
+                    run_history::stop_confirmation(pending),
+                    Message::CancelStopSandbox,
+                );
+            }
+        }
+
+        if self.pending_stop_all && self.view_mode == ViewMode::RunHistory {
+            let running = self.run_history.iter().filter(|r| r.is_running()).count();
+            return modal::view(
+                base,
+                run_history::stop_all_confirmation(running),
+                Message::CancelStopAll,
+            );
+        }
+
+        if self.pending_clear_history && self.view_mode == ViewMode::RunHistory {
+            let finished = self.run_history.iter().filter(|r| !r.is_running()).count();
+            return modal::view(
+                base,
+                run_history::clear_confirmation(finished),
+                Message::CancelClearHistory,
+            );
+        }
+
+        if let Some(details) = &self.sandbox_details {
+            if self.view_mode == ViewMode::RunHistory {
+                return modal::view(
+                    base,
+                    run_history::sandbox_details(details),
+                    Message::CloseSandboxDetails,
+                );
+            }
+        }
+
+        if self.show_template_gallery && self.view_mode == ViewMode::ProfileList {
+            return modal::view(
+                base,
+                profile_list::template_gallery(),
+                Message::CloseTemplateGallery,
+            );
+        }
+
+        if let Some(edit) = self
+            .bulk_edit
+            .filter(|_| self.view_mode == ViewMode::ProfileList)
+        {
+            let targets = self.bulk_edit_targets(&edit);
+            let preview = targets
+                .iter()
+                .map(|(idx, updated)| {
+                    let current = &self.profiles[*idx];
+                    (current.name.clone(), current.diff(updated))
+                })
+                .collect();
+            return modal::view(
+                base,
+                profile_list::bulk_edit(edit, preview, self.bulk_edit_conflict(&targets)),
+                Message::CancelBulkEdit,
+            );
+        }
+
+        if let Some(index) = self.pending_delete {
+            if let Some(profile) = self.profiles.get(index) {
+                return modal::view(
+                    base,
+                    profile_list::delete_confirmation(&profile.name, index),
+                    Message::CancelDeleteProfile,
+                );
+            }
+        }
+
+        base
     }
 
     fn view_sidebar(&self) -> Element<'_, Message> {
-        use iced::widget::{button, column, text};
+        use iced::widget::{button, column, text, vertical_space};
 
         let title = text("HOPS").size(28);
 
@@ -590,33 +3362,28 @@ impl HopsGui {
             }),
         };
 
-        let profiles_btn = button(text("📋 Profiles"))
-            .on_press(Message::SwitchView(ViewMode::ProfileList))
+        let profiles_btn = self.nav_button("📋 Profiles", ViewMode::ProfileList);
+        let history_btn = self.nav_button("📜 Run History", ViewMode::RunHistory);
+        let compare_btn = self.nav_button("⇄ Compare", ViewMode::Compare);
+        let settings_btn = self.nav_button("⚙ Settings", ViewMode::Settings);
+
+        let theme_btn = button(text(format!("🌓 Theme: {}", self.settings.theme)).size(14))
+            .on_press(Message::ThemeChanged(self.settings.theme.next()))
             .width(Length::Fill)
-            .padding(12)
-            .style(move |_theme, status| {
-                let is_active = self.view_mode == ViewMode::ProfileList;
-                let base_color = if is_active {
-                    iced::Color::from_rgb(0.25, 0.45, 0.65)
-                } else {
-                    iced::Color::from_rgb(0.18, 0.18, 0.2)
-                };
-                let hover_color = if is_active {
-                    iced::Color::from_rgb(0.3, 0.5, 0.7)
-                } else {
-                    iced::Color::from_rgb(0.22, 0.22, 0.25)
-                };
+            .padding(10)
+            .style(|theme, status| {
+                let palette = theme::palette(theme);
                 iced::widget::button::Style {
                     background: Some(iced::Background::Color(
                         if matches!(status, iced::widget::button::Status::Hovered) {
-                            hover_color
+                            palette.nav_inactive_hover
                         } else {
-                            base_color
-                        }
+                            palette.nav_inactive
+                        },
                     )),
-                    text_color: iced::Color::WHITE,
+                    text_color: palette.text,
                     border: iced::Border {
-                        color: iced::Color::from_rgb(0.35, 0.35, 0.4),
+                        color: palette.nav_border,
                         width: 1.0,
                         radius: 4.0.into(),
                     },
@@ -624,21 +3391,67 @@ impl HopsGui {
                 }
             });
 
-        let history_btn = button(text("📜 Run History"))
-            .on_press(Message::SwitchView(ViewMode::RunHistory))
+        let status: Element<'_, Message> = match &self.connection_error {
+            Some(error) if self.daemon_status == DaemonStatus::Offline => iced::widget::tooltip(
+                status_text,
+                text(error.as_str()).size(12),
+                iced::widget::tooltip::Position::Bottom,
+            )
+            .padding(8)
+            .style(iced::widget::container::rounded_box)
+            .into(),
+            _ => status_text.into(),
+        };
+
+        let sidebar_content = column![
+            title,
+            status,
+            profiles_btn,
+            history_btn,
+            compare_btn,
+            settings_btn,
+            vertical_space(),
+            theme_btn
+        ]
+        .spacing(15)
+        .padding(20)
+        .width(200)
+        .height(Length::Fill);
+
+        container(sidebar_content)
+            .width(Length::Fixed(200.0))
+            .height(Length::Fill)
+            .style(|theme: &Theme| container::Style {
+                background: Some(iced::Background::Color(theme::palette(theme).sidebar)),
+                border: iced::Border {
+                    color: theme::palette(theme).sidebar_border,
+                    width: 0.0,
+                    radius: 0.0.into(),
+                },
+                ..Default::default()
+            })
+            .into()
+    }
+
+    fn nav_button<'a>(&self, label: &'a str, mode: ViewMode) -> Element<'a, Message> {
+        use iced::widget::{button, text};
+
+        let is_active = self.view_mode == mode;
+        button(text(label))
+            .on_press(Message::SwitchView(mode))
             .width(Length::Fill)
             .padding(12)
-            .style(move |_theme, status| {
-                let is_active = self.view_mode == ViewMode::RunHistory;
+            .style(move |theme, status| {
+                let palette = theme::palette(theme);
                 let base_color = if is_active {
                     iced::Color::from_rgb(0.25, 0.45, 0.65)
                 } else {
-                    iced::Color::from_rgb(0.18, 0.18, 0.2)
+                    palette.nav_inactive
                 };
                 let hover_color = if is_active {
                     iced::Color::from_rgb(0.3, 0.5, 0.7)
                 } else {
-                    iced::Color::from_rgb(0.22, 0.22, 0.25)
+                    palette.nav_inactive_hover
                 };
                 iced::widget::button::Style {
                     background: Some(iced::Background::Color(
@@ -646,36 +3459,20 @@ impl HopsGui {
                             hover_color
                         } else {
                             base_color
-                        }
+                        },
                     )),
-                    text_color: iced::Color::WHITE,
+                    text_color: if is_active {
+                        iced::Color::WHITE
+                    } else {
+                        palette.text
+                    },
                     border: iced::Border {
-                        color: iced::Color::from_rgb(0.35, 0.35, 0.4),
+                        color: palette.nav_border,
                         width: 1.0,
                         radius: 4.0.into(),
                     },
                     ..Default::default()
                 }
-            });
-
-        let sidebar_content = column![title, status_text, profiles_btn, history_btn]
-            .spacing(15)
-            .padding(20)
-            .width(200);
-
-        container(sidebar_content)
-            .width(Length::Fixed(200.0))
-            .height(Length::Fill)
-            .style(|_theme: &Theme| container::Style {
-                background: Some(iced::Background::Color(iced::Color::from_rgb(
-                    0.12, 0.12, 0.12,
-                ))),
-                border: iced::Border {
-                    color: iced::Color::from_rgb(0.25, 0.25, 0.25),
-                    width: 0.0,
-                    radius: 0.0.into(),
-                },
-                ..Default::default()
             })
             .into()
     }
@@ -685,5 +3482,103 @@ fn format_timestamp(unix_seconds: i64) -> String {
     if unix_seconds == 0 {
         return "N/A".to_string();
     }
-    "timestamp".to_string()
+
+    let days_since_epoch = unix_seconds.div_euclid(SECONDS_PER_DAY);
+    let seconds_of_day = unix_seconds.rem_euclid(SECONDS_PER_DAY);
+    let shifted_days = days_since_epoch + 719_468;
+    let era = shifted_days.div_euclid(146_097);
+    let day_of_era = shifted_days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds_of_day / 3_600,
+        seconds_of_day % 3_600 / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_timestamp_renders_utc_date_and_time() {
+        assert_eq!(format_timestamp(0), "N/A");
+        assert_eq!(format_timestamp(1), "1970-01-01 00:00 UTC");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00 UTC");
+        assert_eq!(format_timestamp(1_792_108_799), "2026-10-15 23:59 UTC");
+    }
+
+    #[test]
+    fn format_memory_value_in_bytes_is_exact() {
+        assert_eq!(format_memory_value(0, MemoryUnit::Bytes), "0");
+        assert_eq!(format_memory_value(1, MemoryUnit::Bytes), "1");
+        assert_eq!(format_memory_value(1536, MemoryUnit::Bytes), "1536");
+    }
+
+    #[test]
+    fn format_memory_value_in_kb_rounds_to_two_decimals() {
+        assert_eq!(format_memory_value(0, MemoryUnit::KB), "0");
+        assert_eq!(format_memory_value(5, MemoryUnit::KB), "0");
+        assert_eq!(format_memory_value(6, MemoryUnit::KB), "0.01");
+        assert_eq!(format_memory_value(1023, MemoryUnit::KB), "1");
+        assert_eq!(format_memory_value(1024, MemoryUnit::KB), "1");
+        assert_eq!(format_memory_value(1536, MemoryUnit::KB), "1.5");
+        assert_eq!(format_memory_value(10 * 1024, MemoryUnit::KB), "10");
+    }
+
+    #[test]
+    fn format_memory_value_in_mb_rounds_to_two_decimals() {
+        let mb = 1024 * 1024;
+        assert_eq!(format_memory_value(0, MemoryUnit::MB), "0");
+        assert_eq!(format_memory_value(mb - 1, MemoryUnit::MB), "1");
+        assert_eq!(format_memory_value(mb, MemoryUnit::MB), "1");
+        assert_eq!(format_memory_value(mb + mb / 4, MemoryUnit::MB), "1.25");
+        assert_eq!(format_memory_value(mb + mb / 3, MemoryUnit::MB), "1.33");
+        assert_eq!(format_memory_value(512 * mb, MemoryUnit::MB), "512");
+    }
+
+    #[test]
+    fn format_memory_value_in_gb_rounds_to_three_decimals() {
+        let gb = 1024 * 1024 * 1024;
+        assert_eq!(format_memory_value(0, MemoryUnit::GB), "0");
+        assert_eq!(format_memory_value(gb, MemoryUnit::GB), "1");
+        assert_eq!(format_memory_value(2 * gb - 1, MemoryUnit::GB), "2");
+        assert_eq!(format_memory_value(gb + gb / 2, MemoryUnit::GB), "1.5");
+        assert_eq!(format_memory_value(gb + gb / 1000, MemoryUnit::GB), "1.001");
+        assert_eq!(format_memory_value(gb + gb / 3, MemoryUnit::GB), "1.333");
+        assert_eq!(format_memory_value(10 * gb, MemoryUnit::GB), "10");
+    }
+
+    #[test]
+    fn fractional_memory_display_matches_the_limit_sent_to_hopsd() {
+        let bytes = MemoryUnit::GB.to_bytes(1.5);
+        let mut policy = Policy::default();
+        policy.capabilities.resource_limits.memory_bytes = Some(bytes);
+
+        let sent = crate::grpc_client::convert_policy_to_proto(&policy)
+            .resources
+            .unwrap_or_default()
+            .memory;
+
+        assert_eq!(format_memory_value(bytes, MemoryUnit::GB), "1.5");
+        assert_eq!(
+            sent,
+            format!("{}M", format_memory_value(bytes, MemoryUnit::MB))
+        );
+        assert_eq!(sent, "1536M");
+    }
 }
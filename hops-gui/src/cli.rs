@@ -0,0 +1,139 @@
+use crate::grpc_client::hops::SandboxState;
+use crate::grpc_client::GrpcClient;
+use crate::utils::config;
+use crate::utils::settings::{self, SandboxLaunch};
+use std::time::Duration;
+
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const USAGE: &str = "Usage: hops-gui --profile <name> -- <command...>";
+
+pub struct RunArgs {
+    pub profile: String,
+    pub command: Vec<String>,
+}
+
+pub fn parse_run_args(args: &[String]) -> Option<Result<RunArgs, String>> {
+    let flag = args.iter().position(|a| a == "--profile")?;
+    let Some(profile) = args.get(flag + 1).filter(|p| !p.starts_with("--")) else {
+        return Some(Err(format!("--profile requires a profile name\n{}", USAGE)));
+    };
+    let command: Vec<String> = match args.iter().position(|a| a == "--") {
+        Some(separator) => args[separator + 1..].to_vec(),
+        None => Vec::new(),
+    };
+    if command.is_empty() {
+        return Some(Err(format!("No command given\n{}", USAGE)));
+    }
+    Some(Ok(RunArgs {
+        profile: profile.clone(),
+        command,
+    }))
+}
+
+pub fn parse_open_arg(args: &[String]) -> Option<String> {
+    let flag = args.iter().position(|a| a == "--open")?;
+    args.get(flag + 1).filter(|p| !p.starts_with("--")).cloned()
+}
+
+pub fn run(args: RunArgs) -> i32 {
+    let profiles = match config::load_profiles() {
+        Ok(profiles) => profiles,
+        Err(e) => {
+            eprintln!("Failed to load profiles: {}", e);
+            return 2;
+        }
+    };
+    let Some(policy) = profiles.into_iter().find(|p| p.name == args.profile) else {
+        eprintln!("Profile \"{}\" not found", args.profile);
+        return 2;
+    };
+    let mut settings = settings::load_settings();
+    if let Some(issue) = policy
+        .validate(settings.connection_mode)
+        .into_iter()
+        .find(|i| i.is_error())
+    {
+        eprintln!("Cannot run \"{}\": {}", policy.name, issue.message);
+        return 2;
+    }
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Failed to start async runtime: {}", e);
+            return 1;
+        }
+    };
+
+    runtime.block_on(async {
+        let mut client = match GrpcClient::connect_with_settings(&settings).await {
+            Ok(client) => client,
+            Err(e) => {
+                eprintln!("{}", e);
+                return 1;
+            }
+        };
+
+        let working_dir = policy.sandbox.working_directory().map(str::to_string);
+        let response = match client
+            .run_sandbox(&policy, args.command.clone(), working_dir)
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                eprintln!("Run failed: {}", e);
+                return 1;
+            }
+        };
+        if !response.success {
+            eprintln!(
+                "Run failed: {}",
+                response
+                    .error
+                    .as_deref()
+                    .unwrap_or("daemon reported failure")
+            );
+            return 1;
+        }
+
+        println!("sandbox_id: {}", response.sandbox_id);
+        println!("pid: {}", response.pid);
+        settings.sandbox_launches.insert(
+            response.sandbox_id.clone(),
+            SandboxLaunch {
+                profile: policy.name.clone(),
+                command: args.command.join(" "),
+            },
+        );
+        if let Err(e) = settings::save_settings(&settings) {
+            eprintln!(
+                "Failed to save settings: {}. The GUI will not show the profile and command for this run",
+                e
+            );
+        }
+
+        loop {
+            let status = match client.get_status(response.sandbox_id.clone()).await {
+                Ok(status) => status,
+                Err(e) => {
+                    eprintln!("Failed to get sandbox status: {}", e);
+                    return 1;
+                }
+            };
+            let state = status.state();
+            if matches!(state, SandboxState::Starting | SandboxState::Running) {
+                tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+                continue;
+            }
+            println!("state: {}", state.as_str_name());
+            if let Some(code) = status.exit_code {
+                println!("exit_code: {}", code);
+            }
+            return match (state, status.exit_code) {
+                (_, Some(code)) => code,
+                (SandboxState::Stopped, None) => 0,
+                _ => 1,
+            };
+        }
+    })
+}
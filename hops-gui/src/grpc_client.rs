@@ -1,6 +1,8 @@
+use crate::utils::settings::{ConnectionMode, Settings};
 use hyper_util::rt::TokioIo;
 use prost_types::Timestamp;
 use std::path::PathBuf;
+use std::time::Duration;
 use tonic::transport::{Endpoint, Uri};
 use tower::service_fn;
 
@@ -10,12 +12,19 @@ pub mod hops {
 
 use hops::hops_service_client::HopsServiceClient;
 use hops::{
-    ListRequest, RunRequest, SandboxInfo, SandboxStatus, StatusRequest, StopRequest,
+    DaemonStatusRequest, ListRequest, RunRequest, SandboxInfo, SandboxStatus, StatusRequest,
+    StopRequest,
 };
 
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+pub const PROTOCOL_VERSION: u32 = 1;
+
 #[derive(Debug)]
 pub enum GrpcError {
     ConnectionFailed(String),
+    SocketNotFound(PathBuf),
+    ConnectionRefused(String),
+    Timeout(String),
     RequestFailed(String),
     InvalidResponse(String),
 }
@@ -24,6 +33,22 @@ impl std::fmt::Display for GrpcError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             GrpcError::ConnectionFailed(msg) => write!(f, "Connection failed: {}", msg),
+            GrpcError::SocketNotFound(path) => write!(
+                f,
+                "Daemon socket not found at {}. Is hopsd running?",
+                path.display()
+            ),
+            GrpcError::ConnectionRefused(target) => write!(
+                f,
+                "Connection refused by {}. hopsd may have exited; try restarting it",
+                target
+            ),
+            GrpcError::Timeout(target) => write!(
+                f,
+                "Timed out after {}s connecting to {}. hopsd may be hung",
+                CONNECT_TIMEOUT.as_secs(),
+                target
+            ),
             GrpcError::RequestFailed(msg) => write!(f, "Request failed: {}", msg),
             GrpcError::InvalidResponse(msg) => write!(f, "Invalid response: {}", msg),
         }
@@ -38,29 +63,97 @@ pub struct GrpcClient {
 }
 
 impl GrpcClient {
-    pub async fn connect() -> Result<Self, GrpcError> {
-        let socket_path = dirs::home_dir()
+    pub fn default_socket_path() -> Result<PathBuf, GrpcError> {
+        Ok(dirs::home_dir()
             .ok_or_else(|| GrpcError::ConnectionFailed("Cannot determine home directory".into()))?
             .join(".hops")
-            .join("hops.sock");
+            .join("hops.sock"))
+    }
+
+    pub async fn connect() -> Result<Self, GrpcError> {
+        Self::connect_with_path(Self::default_socket_path()?).await
+    }
+
+    pub async fn connect_with_settings(settings: &Settings) -> Result<Self, GrpcError> {
+        match (settings.connection_mode, &settings.socket_path) {
+            (ConnectionMode::Tcp, _) => {
+                Self::connect_tcp(settings.tcp_address.as_deref().unwrap_or_default()).await
+            }
+            (ConnectionMode::UnixSocket, Some(path)) => {
+                Self::connect_with_path(PathBuf::from(path)).await
+            }
+            (ConnectionMode::UnixSocket, None) => Self::connect().await,
+        }
+    }
+
+    pub fn validate_tcp_address(address: &str) -> Result<Uri, GrpcError> {
+        let uri: Uri = address.trim().parse().map_err(|_| {
+            GrpcError::ConnectionFailed(format!(
+                "Invalid daemon address '{}'. Expected http://host:port",
+                address
+            ))
+        })?;
+
+        match uri.scheme_str() {
+            Some("http") => {}
+            Some(scheme) => {
+                return Err(GrpcError::ConnectionFailed(format!(
+                    "Unsupported scheme '{}://'. Use http://host:port (tunnel TLS externally)",
+                    scheme
+                )))
+            }
+            None => {
+                return Err(GrpcError::ConnectionFailed(format!(
+                    "Missing scheme in '{}'. Use http://host:port",
+                    address
+                )))
+            }
+        }
 
+        let has_host = uri.host().is_some_and(|host| !host.is_empty());
+        if !has_host || uri.port_u16().is_none() {
+            return Err(GrpcError::ConnectionFailed(format!(
+                "Daemon address '{}' must include a host and port",
+                address
+            )));
+        }
+
+        Ok(uri)
+    }
+
+    pub async fn connect_tcp(address: &str) -> Result<Self, GrpcError> {
+        let uri = Self::validate_tcp_address(address)?;
+
+        let target = uri.to_string();
+        let channel = tokio::time::timeout(CONNECT_TIMEOUT, Endpoint::from(uri).connect())
+            .await
+            .map_err(|_| GrpcError::Timeout(target.clone()))?
+            .map_err(|e| classify_connect_error(&target, e))?;
+
+        Ok(Self {
+            client: HopsServiceClient::new(channel),
+        })
+    }
+
+    pub async fn connect_with_path(socket_path: PathBuf) -> Result<Self, GrpcError> {
         if !socket_path.exists() {
-            return Err(GrpcError::ConnectionFailed(
-                "Daemon socket not found. Is hopsd running?".into(),
-            ));
+            return Err(GrpcError::SocketNotFound(socket_path));
         }
 
-        let channel = Endpoint::try_from("http://[::]:50051")
-            .map_err(|e| GrpcError::ConnectionFailed(format!("Invalid endpoint: {}", e)))?
-            .connect_with_connector(service_fn(move |_: Uri| {
-                let path = socket_path.clone();
-                async move {
-                    let stream = tokio::net::UnixStream::connect(path).await?;
-                    Ok::<_, std::io::Error>(TokioIo::new(stream))
-                }
-            }))
+        let target = socket_path.display().to_string();
+        let endpoint = Endpoint::try_from("http://[::]:50051")
+            .map_err(|e| GrpcError::ConnectionFailed(format!("Invalid endpoint: {}", e)))?;
+        let connect = endpoint.connect_with_connector(service_fn(move |_: Uri| {
+            let path = socket_path.clone();
+            async move {
+                let stream = tokio::net::UnixStream::connect(path).await?;
+                Ok::<_, std::io::Error>(TokioIo::new(stream))
+            }
+        }));
+        let channel = tokio::time::timeout(CONNECT_TIMEOUT, connect)
             .await
-            .map_err(|e| GrpcError::ConnectionFailed(format!("Failed to connect: {}", e)))?;
+            .map_err(|_| GrpcError::Timeout(target.clone()))?
+            .map_err(|e| classify_connect_error(&target, e))?;
 
         Ok(Self {
             client: HopsServiceClient::new(channel),
@@ -73,17 +166,7 @@ impl GrpcClient {
         command: Vec<String>,
         working_dir: Option<String>,
     ) -> Result<RunSandboxResponse, GrpcError> {
-        let proto_policy = convert_policy_to_proto(policy);
-
-        let request = tonic::Request::new(RunRequest {
-            command,
-            policy_path: None,
-            inline_policy: Some(proto_policy),
-            environment: std::collections::HashMap::new(),
-            working_directory: working_dir,
-            keep: false,
-            allocate_tty: false,
-        });
+        let request = tonic::Request::new(run_request(policy, command, working_dir));
 
         let response = self
             .client
@@ -148,6 +231,50 @@ impl GrpcClient {
 
         Ok(response)
     }
+
+    pub async fn check_daemon_version(&mut self) -> Result<Option<String>, GrpcError> {
+        let request = tonic::Request::new(DaemonStatusRequest {});
+
+        let response = match self.client.get_daemon_status(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == tonic::Code::Unimplemented => {
+                return Ok(Some(outdated_daemon_message()))
+            }
+            Err(e) => {
+                return Err(GrpcError::RequestFailed(format!(
+                    "GetDaemonStatus RPC failed: {}",
+                    e
+                )))
+            }
+        };
+
+        if response.protocol_version == 0 {
+            return Ok(Some(outdated_daemon_message()));
+        }
+        if response.protocol_version == PROTOCOL_VERSION {
+            return Ok(None);
+        }
+        let daemon_version = if response.version.is_empty() {
+            "unknown"
+        } else {
+            response.version.as_str()
+        };
+        Ok(Some(format!(
+            "Daemon version {} (protocol {}) is incompatible with GUI version {} (protocol {}). Update hopsd or hops-gui so they match",
+            daemon_version,
+            response.protocol_version,
+            env!("CARGO_PKG_VERSION"),
+            PROTOCOL_VERSION
+        )))
+    }
+}
+
+fn outdated_daemon_message() -> String {
+    format!(
+        "Daemon did not report a protocol version, so it predates GUI version {} (protocol {}) and some requests may fail. Update hopsd to the same release as hops-gui",
+        env!("CARGO_PKG_VERSION"),
+        PROTOCOL_VERSION
+    )
 }
 
 #[derive(Debug, Clone)]
@@ -164,7 +291,29 @@ pub struct StopSandboxResponse {
     pub error: Option<String>,
 }
 
-fn convert_policy_to_proto(policy: &crate::models::policy::Policy) -> hops::Policy {
+fn classify_connect_error(target: &str, error: tonic::transport::Error) -> GrpcError {
+    let mut source = std::error::Error::source(&error);
+    while let Some(cause) = source {
+        if let Some(io_error) = cause.downcast_ref::<std::io::Error>() {
+            match io_error.kind() {
+                std::io::ErrorKind::ConnectionRefused => {
+                    return GrpcError::ConnectionRefused(target.to_string())
+                }
+                std::io::ErrorKind::PermissionDenied => {
+                    return GrpcError::ConnectionFailed(format!(
+                        "Permission denied opening {}",
+                        target
+                    ))
+                }
+                _ => {}
+            }
+        }
+        source = cause.source();
+    }
+    GrpcError::ConnectionFailed(format!("Failed to connect to {}: {}", target, error))
+}
+
+pub fn convert_policy_to_proto(policy: &crate::models::policy::Policy) -> hops::Policy {
     use crate::models::capability::{FilesystemCapability, NetworkCapability};
 
     let network_access = match policy.capabilities.network {
@@ -178,16 +327,13 @@ fn convert_policy_to_proto(policy: &crate::models::policy::Policy) -> hops::Poli
     let mut fs_write = Vec::new();
     let mut fs_execute = Vec::new();
 
-    for cap in &policy.capabilities.filesystem {
-        match cap {
-            FilesystemCapability::Read => {
-                fs_read.extend(policy.capabilities.allowed_paths.clone())
-            }
-            FilesystemCapability::Write => {
-                fs_write.extend(policy.capabilities.allowed_paths.clone())
-            }
-            FilesystemCapability::Execute => {
-                fs_execute.extend(policy.capabilities.allowed_paths.clone())
+    for entry in &policy.capabilities.allowed_paths {
+        let path = &entry.path;
+        for cap in policy.capabilities.capabilities_for(path) {
+            match cap {
+                FilesystemCapability::Read => fs_read.push(path.clone()),
+                FilesystemCapability::Write => fs_write.push(path.clone()),
+                FilesystemCapability::Execute => fs_execute.push(path.clone()),
             }
         }
     }
@@ -198,9 +344,17 @@ fn convert_policy_to_proto(policy: &crate::models::policy::Policy) -> hops::Poli
         execute: fs_execute,
     };
 
+    let outbound_allowlist = if policy.capabilities.network.allows_outbound() {
+        policy.capabilities.outbound_allowlist.clone()
+    } else {
+        Vec::new()
+    };
+
     let capabilities = hops::Capabilities {
         network: network_access,
         filesystem: Some(filesystem),
+        outbound_allowlist,
+        dns: Some(policy.capabilities.dns_enabled()),
     };
 
     let resources = hops::ResourceLimits {
@@ -220,19 +374,74 @@ fn convert_policy_to_proto(policy: &crate::models::policy::Policy) -> hops::Poli
     }
 }
 
+fn run_request(
+    policy: &crate::models::policy::Policy,
+    command: Vec<String>,
+    working_dir: Option<String>,
+) -> RunRequest {
+    RunRequest {
+        command,
+        policy_path: None,
+        inline_policy: Some(convert_policy_to_proto(policy)),
+        environment: policy.sandbox.environment.clone(),
+        working_directory: working_dir,
+        keep: false,
+        allocate_tty: false,
+    }
+}
+
+pub fn policy_preview_json(policy: &crate::models::policy::Policy) -> String {
+    let request = run_request(
+        policy,
+        Vec::new(),
+        policy.sandbox.working_directory().map(str::to_string),
+    );
+    let environment: std::collections::BTreeMap<_, _> = request.environment.into_iter().collect();
+    let proto = request.inline_policy.unwrap_or_default();
+    let sandbox = proto.sandbox.unwrap_or_default();
+    let capabilities = proto.capabilities.unwrap_or_default();
+    let filesystem = capabilities.filesystem.clone().unwrap_or_default();
+    let resources = proto.resources.unwrap_or_default();
+
+    let preview = serde_json::json!({
+        "sandbox": {
+            "root": sandbox.root,
+        },
+        "environment": environment,
+        "working_directory": request.working_directory,
+        "capabilities": {
+            "network": capabilities.network().as_str_name(),
+            "dns": capabilities.dns,
+            "outbound_allowlist": capabilities.outbound_allowlist,
+            "filesystem": {
+                "read": filesystem.read,
+                "write": filesystem.write,
+                "execute": filesystem.execute,
+            },
+        },
+        "resources": {
+            "cpus": resources.cpus,
+            "memory": resources.memory,
+            "max_processes": resources.max_processes,
+        },
+    });
+    serde_json::to_string_pretty(&preview).unwrap_or_else(|_| format!("{:#?}", preview))
+}
+
+pub fn policy_proto_debug(policy: &crate::models::policy::Policy) -> String {
+    format!("{:#?}", convert_policy_to_proto(policy))
+}
+
 fn format_memory(bytes: Option<u64>) -> String {
+    const UNITS: [(u64, &str); 3] = [(1024 * 1024 * 1024, "G"), (1024 * 1024, "M"), (1024, "K")];
     match bytes {
-        Some(b) => {
-            if b >= 1024 * 1024 * 1024 {
-                format!("{}G", b / (1024 * 1024 * 1024))
-            } else if b >= 1024 * 1024 {
-                format!("{}M", b / (1024 * 1024))
-            } else if b >= 1024 {
-                format!("{}K", b / 1024)
-            } else {
-                format!("{}", b)
-            }
-        }
+        Some(b) => UNITS
+            .iter()
+            .find(|(size, _)| b >= *size && b % size == 0)
+            .map_or_else(
+                || b.to_string(),
+                |(size, suffix)| format!("{}{}", b / size, suffix),
+            ),
         None => "0".to_string(),
     }
 }
@@ -1,54 +1,287 @@
 mod app;
+mod cli;
 mod grpc_client;
 mod models;
+mod theme;
 mod utils;
 mod views;
 
-use app::{HopsGui, Message};
+use app::{HopsGui, Message, MoveDirection, ViewMode};
+use iced::event;
+use iced::futures::SinkExt;
 use iced::keyboard;
+use iced::keyboard::key::Named;
+use iced::window;
 use iced::Event;
-use iced::{Element, Subscription, Task};
+use iced::{Element, Point, Rectangle, Size, Subscription, Task};
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::sync::OnceLock;
+use std::time::Duration;
+use utils::config;
+use utils::settings::{self, WindowGeometry};
+
+const PROFILE_WATCH_DEBOUNCE: Duration = Duration::from_millis(200);
+const STATS_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+const MIN_VISIBLE_WINDOW_EDGE: f32 = 80.0;
+
+static SAVED_WINDOW_POSITION: OnceLock<Point> = OnceLock::new();
 
 fn main() -> iced::Result {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match cli::parse_run_args(&args) {
+        Some(Ok(run_args)) => std::process::exit(cli::run(run_args)),
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+        None => {}
+    }
+    let open_profile = cli::parse_open_arg(&args);
+
     iced::application("Hops - Profile Management", update, view)
         .subscription(subscription)
-        .run_with(|| {
-            let (app, task) = HopsGui::new();
+        .theme(theme)
+        .window(window_settings(settings::load_settings().window))
+        .run_with(move || {
+            let (app, task) = HopsGui::new(open_profile);
             (app, task)
         })
 }
 
+fn window_settings(geometry: Option<WindowGeometry>) -> window::Settings {
+    let Some(geometry) = geometry else {
+        return window::Settings::default();
+    };
+    let position = match (geometry.x, geometry.y) {
+        (Some(x), Some(y)) => {
+            let _ = SAVED_WINDOW_POSITION.set(Point::new(x, y));
+            window::Position::SpecificWith(restore_window_position)
+        }
+        _ => window::Position::default(),
+    };
+    window::Settings {
+        size: Size::new(geometry.width, geometry.height),
+        position,
+        ..window::Settings::default()
+    }
+}
+
+fn restore_window_position(window: Size, monitor: Size) -> Point {
+    let saved = SAVED_WINDOW_POSITION.get().copied().unwrap_or_default();
+    let mut monitors = monitor_bounds();
+    if monitors.is_empty() {
+        monitors.push(Rectangle::new(Point::ORIGIN, monitor));
+    }
+    clamp_to_monitors(saved, window, &monitors)
+}
+
+fn clamp_to_monitors(saved: Point, window: Size, monitors: &[Rectangle]) -> Point {
+    let reachable = |monitor: &Rectangle| {
+        saved.x + window.width >= monitor.x + MIN_VISIBLE_WINDOW_EDGE
+            && saved.x <= monitor.x + monitor.width - MIN_VISIBLE_WINDOW_EDGE
+            && saved.y >= monitor.y
+            && saved.y <= monitor.y + monitor.height - MIN_VISIBLE_WINDOW_EDGE
+    };
+    if monitors.iter().any(reachable) {
+        return saved;
+    }
+    let Some(primary) = monitors.first() else {
+        return saved;
+    };
+    Point::new(
+        saved
+            .x
+            .min(primary.x + primary.width - MIN_VISIBLE_WINDOW_EDGE)
+            .max(primary.x + MIN_VISIBLE_WINDOW_EDGE - window.width),
+        saved
+            .y
+            .min(primary.y + primary.height - MIN_VISIBLE_WINDOW_EDGE)
+            .max(primary.y),
+    )
+}
+
+#[cfg(target_os = "macos")]
+fn monitor_bounds() -> Vec<Rectangle> {
+    use core_graphics::display::CGDisplay;
+
+    let main = CGDisplay::main().id;
+    let mut displays = CGDisplay::active_displays().unwrap_or_default();
+    displays.sort_by_key(|id| *id != main);
+    displays
+        .into_iter()
+        .map(|id| {
+            let bounds = CGDisplay::new(id).bounds();
+            Rectangle::new(
+                Point::new(bounds.origin.x as f32, bounds.origin.y as f32),
+                Size::new(bounds.size.width as f32, bounds.size.height as f32),
+            )
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "macos"))]
+fn monitor_bounds() -> Vec<Rectangle> {
+    Vec::new()
+}
+
 fn update(state: &mut HopsGui, message: Message) -> Task<Message> {
-    state.update(message)
+    let task = state.update(message);
+    state.refresh_policy_issues();
+    task
 }
 
 fn view(state: &HopsGui) -> Element<'_, Message> {
     state.view()
 }
 
-fn subscription(_state: &HopsGui) -> Subscription<Message> {
+fn theme(state: &HopsGui) -> iced::Theme {
+    state.settings.theme.resolve()
+}
+
+fn subscription(state: &HopsGui) -> Subscription<Message> {
+    let history_refresh =
+        if state.settings.history_auto_refresh && state.view_mode == ViewMode::RunHistory {
+            iced::time::every(Duration::from_secs(state.settings.history_refresh_secs()))
+                .map(|_| Message::RefreshHistory)
+        } else {
+            Subscription::none()
+        };
+
+    let stats_refresh = if state.view_mode == ViewMode::RunHistory
+        && state.run_history.iter().any(|r| r.is_running())
+    {
+        iced::time::every(STATS_REFRESH_INTERVAL).map(|_| Message::RefreshStats)
+    } else {
+        Subscription::none()
+    };
+
+    Subscription::batch([
+        keyboard_shortcuts(),
+        window_geometry_changes(),
+        history_refresh,
+        stats_refresh,
+        Subscription::run(watch_profiles_dir),
+    ])
+}
+
+fn watch_profiles_dir() -> impl iced::futures::Stream<Item = Message> {
+    iced::stream::channel(10, |mut output| async move {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let watcher = config::get_profiles_dir().ok().and_then(|dir| {
+            let mut watcher = notify::recommended_watcher(move |result| {
+                let _ = sender.send(result);
+            })
+            .ok()?;
+            watcher.watch(&dir, RecursiveMode::NonRecursive).ok()?;
+            Some(watcher)
+        });
+
+        let Some(_watcher) = watcher else {
+            return std::future::pending().await;
+        };
+
+        while let Some(result) = receiver.recv().await {
+            if !result.is_ok_and(|event: notify::Event| is_profile_change(&event)) {
+                continue;
+            }
+            tokio::time::sleep(PROFILE_WATCH_DEBOUNCE).await;
+            while receiver.try_recv().is_ok() {}
+            if output.send(Message::ProfilesChangedOnDisk).await.is_err() {
+                break;
+            }
+        }
+    })
+}
+
+fn is_profile_change(event: &notify::Event) -> bool {
+    matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) && event
+        .paths
+        .iter()
+        .any(|path| path.extension().and_then(|s| s.to_str()) == Some("toml"))
+}
+
+fn window_geometry_changes() -> Subscription<Message> {
     iced::event::listen_with(|event, _status, _id| match event {
+        Event::Window(window::Event::Resized(size)) => Some(Message::WindowResized(size)),
+        Event::Window(window::Event::Moved(position)) => Some(Message::WindowMoved(position)),
+        _ => None,
+    })
+}
+
+fn keyboard_shortcuts() -> Subscription<Message> {
+    iced::event::listen_with(|event, status, _id| match event {
         Event::Keyboard(keyboard::Event::KeyPressed {
-            key: keyboard::Key::Character(c),
+            key: keyboard::Key::Named(named),
             modifiers,
             ..
-        }) => {
+        }) if modifiers.is_empty() && status == event::Status::Ignored => match named {
+            Named::ArrowUp => Some(Message::MoveProfileHighlight(MoveDirection::Up)),
+            Named::ArrowDown => Some(Message::MoveProfileHighlight(MoveDirection::Down)),
+            Named::Enter => Some(Message::OpenHighlightedProfile),
+            _ => None,
+        },
+        Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
             let is_cmd_or_ctrl = if cfg!(target_os = "macos") {
                 modifiers.command()
             } else {
                 modifiers.control()
             };
 
-            if is_cmd_or_ctrl {
-                match c.as_str() {
-                    "s" | "S" => Some(Message::SaveProfile),
-                    "n" | "N" => Some(Message::CreateNewProfile),
-                    _ => None,
+            if !is_cmd_or_ctrl {
+                return None;
+            }
+
+            match key.as_ref() {
+                keyboard::Key::Character("s" | "S") => Some(Message::SaveProfile),
+                keyboard::Key::Character("n" | "N") => Some(Message::CreateNewProfile),
+                keyboard::Key::Character("d" | "D") => Some(Message::DuplicateSelectedProfile),
+                keyboard::Key::Character("1") => Some(Message::SwitchView(ViewMode::ProfileList)),
+                keyboard::Key::Character("2") => Some(Message::SwitchView(ViewMode::RunHistory)),
+                keyboard::Key::Named(Named::Backspace | Named::Delete)
+                    if status == event::Status::Ignored =>
+                {
+                    Some(Message::RequestDeleteSelectedProfile)
                 }
-            } else {
-                None
+                _ => None,
             }
         }
         _ => None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Size = Size::new(1200.0, 800.0);
+
+    fn monitors() -> Vec<Rectangle> {
+        vec![
+            Rectangle::new(Point::ORIGIN, Size::new(1920.0, 1080.0)),
+            Rectangle::new(Point::new(-2560.0, -200.0), Size::new(2560.0, 1440.0)),
+        ]
+    }
+
+    #[test]
+    fn secondary_monitor_position_is_kept() {
+        let saved = Point::new(-2000.0, -100.0);
+
+        assert_eq!(clamp_to_monitors(saved, WINDOW, &monitors()), saved);
+    }
+
+    #[test]
+    fn position_off_every_monitor_is_pulled_onto_primary() {
+        let restored = clamp_to_monitors(Point::new(5000.0, 3000.0), WINDOW, &monitors());
+
+        assert_eq!(
+            restored,
+            Point::new(
+                1920.0 - MIN_VISIBLE_WINDOW_EDGE,
+                1080.0 - MIN_VISIBLE_WINDOW_EDGE
+            )
+        );
+    }
+}
@@ -1,9 +1,17 @@
-use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use crate::models::policy::PolicyIssue;
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct SandboxConfig {
-    #[serde(rename = "root_path")]
+    #[serde(rename = "root_path", default = "default_root_path")]
     pub root_path: String,
     #[serde(default)]
     pub mounts: Vec<MountConfig>,
@@ -11,14 +19,61 @@ pub struct SandboxConfig {
     pub hostname: Option<String>,
     #[serde(default = "default_working_directory")]
     pub working_directory: String,
-    #[serde(default)]
-    pub environment: std::collections::HashMap<String, String>,
+    #[serde(default, deserialize_with = "deserialize_environment")]
+    pub environment: HashMap<String, String>,
+    #[serde(flatten)]
+    #[schemars(skip)]
+    pub extra: BTreeMap<String, toml::Value>,
+}
+
+fn default_root_path() -> String {
+    String::from("/")
 }
 
 fn default_working_directory() -> String {
     String::from("/")
 }
 
+fn deserialize_environment<'de, D>(deserializer: D) -> Result<HashMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct EnvironmentVisitor;
+
+    impl<'de> Visitor<'de> for EnvironmentVisitor {
+        type Value = HashMap<String, String>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a map of environment variables")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut environment = HashMap::new();
+            while let Some((key, value)) = map.next_entry::<String, String>()? {
+                if environment.contains_key(&key) {
+                    return Err(de::Error::custom(format!(
+                        "duplicate environment variable `{}`",
+                        key
+                    )));
+                }
+                environment.insert(key, value);
+            }
+            Ok(environment)
+        }
+    }
+
+    deserializer.deserialize_map(EnvironmentVisitor)
+}
+
+impl SandboxConfig {
+    pub fn working_directory(&self) -> Option<&str> {
+        Some(self.working_directory.as_str()).filter(|dir| !dir.is_empty())
+    }
+}
+
 impl Default for SandboxConfig {
     fn default() -> Self {
         Self {
@@ -26,12 +81,13 @@ impl Default for SandboxConfig {
             mounts: vec![],
             hostname: None,
             working_directory: String::from("/"),
-            environment: std::collections::HashMap::new(),
+            environment: HashMap::new(),
+            extra: BTreeMap::new(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct MountConfig {
     pub source: String,
     pub destination: String,
@@ -47,7 +103,58 @@ fn default_mount_mode() -> MountMode {
     MountMode::ReadOnly
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl MountConfig {
+    pub fn validate(&self) -> Vec<PolicyIssue> {
+        let mut issues = Vec::new();
+        let source = self.source.trim();
+
+        match self.mount_type {
+            MountType::Bind if source.is_empty() => {
+                issues.push(PolicyIssue::error(format!(
+                    "Bind mount at {} has no source path",
+                    self.destination
+                )));
+            }
+            MountType::Bind if !Path::new(source).exists() => {
+                issues.push(PolicyIssue::error(format!(
+                    "Bind mount source {} does not exist",
+                    source
+                )));
+            }
+            MountType::Bind => {}
+            _ if !source.is_empty() && source != self.mount_type.as_str() => {
+                issues.push(PolicyIssue::warning(format!(
+                    "{} mount at {} ignores its source {}",
+                    self.mount_type.as_str(),
+                    self.destination,
+                    source
+                )));
+            }
+            _ => {}
+        }
+
+        if !self.destination.starts_with('/') {
+            issues.push(PolicyIssue::error(format!(
+                "Mount destination {} is not an absolute path",
+                self.destination
+            )));
+        }
+
+        if matches!(self.mount_type, MountType::Tmpfs | MountType::Devtmpfs)
+            && matches!(self.mode, MountMode::ReadOnly)
+        {
+            issues.push(PolicyIssue::warning(format!(
+                "Read-only {} mount at {} starts empty and can never be written",
+                self.mount_type.as_str(),
+                self.destination
+            )));
+        }
+
+        issues
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum MountType {
     Bind,
@@ -57,7 +164,19 @@ pub enum MountType {
     Sysfs,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl MountType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MountType::Bind => "bind",
+            MountType::Tmpfs => "tmpfs",
+            MountType::Devtmpfs => "devtmpfs",
+            MountType::Proc => "proc",
+            MountType::Sysfs => "sysfs",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum MountMode {
     #[serde(rename = "ro")]
@@ -66,34 +185,168 @@ pub enum MountMode {
     ReadWrite,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "PathEntryToml", into = "PathEntryToml")]
+pub struct PathEntry {
+    pub path: String,
+    pub note: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum PathEntryToml {
+    Path(String),
+    Annotated {
+        path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+    },
+}
+
+impl From<PathEntryToml> for PathEntry {
+    fn from(entry: PathEntryToml) -> Self {
+        match entry {
+            PathEntryToml::Path(path) => PathEntry::new(path),
+            PathEntryToml::Annotated { path, note } => PathEntry {
+                path,
+                note: note.filter(|note| !note.trim().is_empty()),
+            },
+        }
+    }
+}
+
+impl From<PathEntry> for PathEntryToml {
+    fn from(entry: PathEntry) -> Self {
+        match entry.note {
+            Some(note) => PathEntryToml::Annotated {
+                path: entry.path,
+                note: Some(note),
+            },
+            None => PathEntryToml::Path(entry.path),
+        }
+    }
+}
+
+impl PathEntry {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            note: None,
+        }
+    }
+}
+
+impl JsonSchema for PathEntry {
+    fn schema_name() -> String {
+        "PathEntry".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        PathEntryToml::json_schema(generator)
+    }
+}
+
+impl fmt::Display for PathEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.path)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct CapabilityGrant {
+    #[serde(default)]
     pub network: NetworkCapability,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns: Option<bool>,
     #[serde(default)]
     pub filesystem: HashSet<FilesystemCapability>,
     #[serde(rename = "allowed_paths", default)]
-    pub allowed_paths: Vec<String>,
+    pub allowed_paths: Vec<PathEntry>,
     #[serde(rename = "denied_paths", default)]
-    pub denied_paths: Vec<String>,
+    pub denied_paths: Vec<PathEntry>,
+    #[serde(
+        rename = "path_capabilities",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub path_capabilities: BTreeMap<String, HashSet<FilesystemCapability>>,
+    #[serde(
+        rename = "outbound_allowlist",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub outbound_allowlist: Vec<String>,
     #[serde(rename = "resource_limits", default)]
     pub resource_limits: ResourceLimits,
+    #[serde(flatten)]
+    #[schemars(skip)]
+    pub extra: BTreeMap<String, toml::Value>,
 }
 
 impl Default for CapabilityGrant {
     fn default() -> Self {
         Self {
             network: NetworkCapability::Disabled,
+            dns: None,
             filesystem: HashSet::new(),
             allowed_paths: vec![],
             denied_paths: vec![],
+            path_capabilities: BTreeMap::new(),
+            outbound_allowlist: vec![],
             resource_limits: ResourceLimits::default(),
+            extra: BTreeMap::new(),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+impl CapabilityGrant {
+    pub fn dns_enabled(&self) -> bool {
+        self.dns.unwrap_or_else(|| self.network.allows_outbound())
+    }
+
+    pub fn capabilities_for(&self, path: &str) -> HashSet<FilesystemCapability> {
+        self.path_capabilities
+            .get(path)
+            .cloned()
+            .unwrap_or_else(|| self.filesystem.clone())
+    }
+
+    pub fn toggle_path_capability(&mut self, path: &str, capability: FilesystemCapability) {
+        let mut capabilities = self.capabilities_for(path);
+        if !capabilities.remove(&capability) {
+            capabilities.insert(capability);
+        }
+        if capabilities == self.filesystem {
+            self.path_capabilities.remove(path);
+        } else {
+            self.path_capabilities
+                .insert(path.to_string(), capabilities);
+        }
+    }
+
+    pub fn normalize(&mut self) {
+        self.allowed_paths = normalize_paths(&self.allowed_paths);
+        self.denied_paths = normalize_paths(&self.denied_paths);
+        let path_capabilities = std::mem::take(&mut self.path_capabilities);
+        for (path, capabilities) in path_capabilities {
+            self.path_capabilities
+                .entry(normalize_path(&path))
+                .or_insert(capabilities);
+        }
+        self.prune_path_capabilities();
+    }
+
+    pub fn prune_path_capabilities(&mut self) {
+        let allowed_paths = &self.allowed_paths;
+        self.path_capabilities
+            .retain(|path, _| allowed_paths.iter().any(|entry| entry.path == *path));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum NetworkCapability {
+    #[default]
     Disabled,
     Outbound,
     Loopback,
@@ -101,6 +354,13 @@ pub enum NetworkCapability {
 }
 
 impl NetworkCapability {
+    pub const ALL: [NetworkCapability; 4] = [
+        NetworkCapability::Disabled,
+        NetworkCapability::Loopback,
+        NetworkCapability::Outbound,
+        NetworkCapability::Full,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             NetworkCapability::Disabled => "disabled",
@@ -110,6 +370,27 @@ impl NetworkCapability {
         }
     }
 
+    pub fn help_text(&self) -> &'static str {
+        match self {
+            NetworkCapability::Disabled => {
+                "All network access blocked, including localhost. DNS lookups fail unless explicitly allowed"
+            }
+            NetworkCapability::Loopback => {
+                "Only connections to services on 127.0.0.1 and ::1 are allowed. Nothing leaves the machine"
+            }
+            NetworkCapability::Outbound => {
+                "The sandbox can open connections to remote hosts but cannot accept incoming ones"
+            }
+            NetworkCapability::Full => {
+                "The sandbox can connect out and listen for incoming connections, like an unsandboxed process"
+            }
+        }
+    }
+
+    pub fn allows_outbound(&self) -> bool {
+        matches!(self, NetworkCapability::Outbound | NetworkCapability::Full)
+    }
+
     pub fn from_str(s: &str) -> Self {
         match s {
             "outbound" => NetworkCapability::Outbound,
@@ -120,7 +401,141 @@ impl NetworkCapability {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+fn normalize_paths(paths: &[PathEntry]) -> Vec<PathEntry> {
+    let mut normalized: Vec<PathEntry> = Vec::with_capacity(paths.len());
+    for entry in paths {
+        let path = normalize_path(&entry.path);
+        match normalized.iter_mut().find(|existing| existing.path == path) {
+            Some(existing) => {
+                if existing.note.is_none() {
+                    existing.note = entry.note.clone();
+                }
+            }
+            None => normalized.push(PathEntry {
+                path,
+                note: entry.note.clone(),
+            }),
+        }
+    }
+    normalized
+}
+
+fn normalize_path(path: &str) -> String {
+    let absolute = path.starts_with('/');
+    let mut parts: Vec<&str> = Vec::new();
+    for component in path.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => match parts.last() {
+                Some(&last) if last != ".." => {
+                    parts.pop();
+                }
+                _ if absolute => {}
+                _ => parts.push(".."),
+            },
+            part => parts.push(part),
+        }
+    }
+    match (absolute, parts.is_empty()) {
+        (true, _) => format!("/{}", parts.join("/")),
+        (false, true) => ".".to_string(),
+        (false, false) => parts.join("/"),
+    }
+}
+
+pub fn validate_outbound_entry(entry: &str) -> Result<(), String> {
+    if let Some((address, prefix)) = entry.split_once('/') {
+        let max_prefix = match address.parse::<IpAddr>() {
+            Ok(IpAddr::V4(_)) => 32,
+            Ok(IpAddr::V6(_)) => 128,
+            Err(_) => return Err(format!("{} (invalid CIDR address)", entry)),
+        };
+        return match prefix.parse::<u8>() {
+            Ok(prefix) if prefix <= max_prefix => Ok(()),
+            _ => Err(format!("{} (invalid CIDR prefix)", entry)),
+        };
+    }
+
+    let (host, port) = match entry.strip_prefix('[') {
+        Some(rest) => match rest.split_once("]:") {
+            Some((host, port)) if host.parse::<Ipv6Addr>().is_ok() => (host, port),
+            _ => return Err(format!("{} (invalid IPv6 address)", entry)),
+        },
+        None => match entry.rsplit_once(':') {
+            Some((host, port)) if is_valid_host(host) => (host, port),
+            Some(_) => return Err(format!("{} (invalid host)", entry)),
+            None => return Err(format!("{} (expected host:port or CIDR)", entry)),
+        },
+    };
+    match port.parse::<u16>() {
+        Ok(port) if port > 0 && !host.is_empty() => Ok(()),
+        _ => Err(format!("{} (invalid port)", entry)),
+    }
+}
+
+pub const MAX_HOSTNAME_LEN: usize = 253;
+pub const MAX_HOSTNAME_LABEL_LEN: usize = 63;
+pub const HOSTNAME_HELP: &str = "Up to 253 characters. Dot-separated labels of 1-63 letters, digits or hyphens, not starting or ending with a hyphen. Leave empty to use the daemon default";
+
+pub fn validate_hostname(hostname: &str) -> Result<(), String> {
+    if hostname.is_empty() {
+        return Ok(());
+    }
+    if hostname.len() > MAX_HOSTNAME_LEN {
+        return Err(format!(
+            "Hostname is longer than {} characters",
+            MAX_HOSTNAME_LEN
+        ));
+    }
+    for label in hostname.split('.') {
+        if label.is_empty() {
+            return Err("Hostname cannot contain empty labels".to_string());
+        }
+        if label.len() > MAX_HOSTNAME_LABEL_LEN {
+            return Err(format!(
+                "Label \"{}\" is longer than {} characters",
+                label, MAX_HOSTNAME_LABEL_LEN
+            ));
+        }
+        if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err("Hostname may only contain letters, digits, hyphens and dots".to_string());
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(format!(
+                "Label \"{}\" cannot start or end with a hyphen",
+                label
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn is_valid_host(host: &str) -> bool {
+    if host.parse::<Ipv4Addr>().is_ok() {
+        return true;
+    }
+    host.len() <= MAX_HOSTNAME_LEN
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= MAX_HOSTNAME_LABEL_LEN
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+impl fmt::Display for NetworkCapability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkCapability::Disabled => write!(f, "Disabled"),
+            NetworkCapability::Loopback => write!(f, "Loopback"),
+            NetworkCapability::Outbound => write!(f, "Outbound"),
+            NetworkCapability::Full => write!(f, "Full"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum FilesystemCapability {
     Read,
@@ -128,7 +543,55 @@ pub enum FilesystemCapability {
     Execute,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl FilesystemCapability {
+    pub const ALL: [FilesystemCapability; 3] = [
+        FilesystemCapability::Read,
+        FilesystemCapability::Write,
+        FilesystemCapability::Execute,
+    ];
+
+    pub const SCOPE_HELP: &'static str = "Defaults apply to every allowed path without its own override; denied paths are blocked regardless";
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilesystemCapability::Read => "read",
+            FilesystemCapability::Write => "write",
+            FilesystemCapability::Execute => "execute",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FilesystemCapability::Read => "Read",
+            FilesystemCapability::Write => "Write",
+            FilesystemCapability::Execute => "Execute",
+        }
+    }
+
+    pub fn help_text(&self) -> &'static str {
+        match self {
+            FilesystemCapability::Read => {
+                "List directories and read file contents under the allowed path. Without it, files there cannot be opened"
+            }
+            FilesystemCapability::Write => {
+                "Create, modify, rename and delete files under the allowed path. Denied paths stay protected"
+            }
+            FilesystemCapability::Execute => {
+                "Run binaries and scripts located under the allowed path. Programs still need Read to load their own files"
+            }
+        }
+    }
+
+    pub fn short_label(&self) -> &'static str {
+        match self {
+            FilesystemCapability::Read => "R",
+            FilesystemCapability::Write => "W",
+            FilesystemCapability::Execute => "X",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ResourceLimits {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cpus: Option<u32>,
@@ -136,6 +599,9 @@ pub struct ResourceLimits {
     pub memory_bytes: Option<u64>,
     #[serde(rename = "max_processes", skip_serializing_if = "Option::is_none")]
     pub max_processes: Option<u32>,
+    #[serde(flatten)]
+    #[schemars(skip)]
+    pub extra: BTreeMap<String, toml::Value>,
 }
 
 impl Default for ResourceLimits {
@@ -144,6 +610,90 @@ impl Default for ResourceLimits {
             cpus: None,
             memory_bytes: None,
             max_processes: None,
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grant_with_path(path: &str) -> CapabilityGrant {
+        CapabilityGrant {
+            filesystem: HashSet::from([FilesystemCapability::Read]),
+            allowed_paths: vec![PathEntry::new(path)],
+            ..CapabilityGrant::default()
         }
     }
+
+    #[test]
+    fn paths_follow_global_filesystem_until_toggled() {
+        let mut grant = grant_with_path("/data");
+        grant.filesystem.insert(FilesystemCapability::Write);
+        grant.prune_path_capabilities();
+
+        assert!(grant.path_capabilities.is_empty());
+        assert!(grant
+            .capabilities_for("/data")
+            .contains(&FilesystemCapability::Write));
+    }
+
+    #[test]
+    fn toggling_back_to_global_drops_path_override() {
+        let mut grant = grant_with_path("/data");
+
+        grant.toggle_path_capability("/data", FilesystemCapability::Execute);
+        assert_eq!(
+            grant.path_capabilities.get("/data"),
+            Some(&HashSet::from([
+                FilesystemCapability::Read,
+                FilesystemCapability::Execute
+            ]))
+        );
+
+        grant.toggle_path_capability("/data", FilesystemCapability::Execute);
+        assert!(grant.path_capabilities.is_empty());
+    }
+
+    #[test]
+    fn normalize_drops_overrides_for_removed_paths() {
+        let mut grant = grant_with_path("/data");
+        grant.path_capabilities.insert(
+            "/data".to_string(),
+            HashSet::from([FilesystemCapability::Read]),
+        );
+        grant.path_capabilities.insert(
+            "/gone".to_string(),
+            HashSet::from([FilesystemCapability::Write]),
+        );
+
+        grant.normalize();
+
+        assert_eq!(
+            grant.path_capabilities.keys().collect::<Vec<_>>(),
+            vec!["/data"]
+        );
+    }
+
+    #[test]
+    fn global_toggle_round_trip_keeps_path_overrides() {
+        let mut grant = grant_with_path("/data");
+        grant.allowed_paths.push(PathEntry::new("/work"));
+        grant.filesystem.insert(FilesystemCapability::Write);
+        grant.toggle_path_capability("/data", FilesystemCapability::Write);
+
+        grant.filesystem.remove(&FilesystemCapability::Write);
+        grant.prune_path_capabilities();
+        grant.filesystem.insert(FilesystemCapability::Write);
+        grant.prune_path_capabilities();
+
+        assert_eq!(
+            grant.capabilities_for("/data"),
+            HashSet::from([FilesystemCapability::Read])
+        );
+        assert!(grant
+            .capabilities_for("/work")
+            .contains(&FilesystemCapability::Write));
+    }
 }
@@ -1,2 +1,3 @@
 pub mod capability;
 pub mod policy;
+pub mod templates;
@@ -1,19 +1,33 @@
-use crate::models::capability::{CapabilityGrant, SandboxConfig};
+use crate::models::capability::{
+    validate_hostname, validate_outbound_entry, CapabilityGrant, FilesystemCapability, PathEntry,
+    SandboxConfig,
+};
+use crate::utils::settings::ConnectionMode;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
+use std::time::SystemTime;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Policy {
     #[serde(skip)]
     pub name: String,
+    #[serde(skip)]
+    pub modified: Option<SystemTime>,
     #[serde(default = "default_version")]
     pub version: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(default)]
     pub capabilities: CapabilityGrant,
+    #[serde(default)]
     pub sandbox: SandboxConfig,
     #[serde(default)]
     pub metadata: HashMap<String, String>,
+    #[serde(flatten)]
+    #[schemars(skip)]
+    pub extra: BTreeMap<String, toml::Value>,
 }
 
 fn default_version() -> String {
@@ -24,11 +38,543 @@ impl Default for Policy {
     fn default() -> Self {
         Self {
             name: String::from("default"),
+            modified: None,
             version: String::from("1.0.0"),
             description: None,
             capabilities: CapabilityGrant::default(),
             sandbox: SandboxConfig::default(),
             metadata: HashMap::new(),
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldChange {
+    Added(String),
+    Removed(String),
+    Changed { from: String, to: String },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    pub field: String,
+    pub change: FieldChange,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSeverity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyIssue {
+    pub severity: IssueSeverity,
+    pub message: String,
+}
+
+impl PolicyIssue {
+    pub fn warning(message: String) -> Self {
+        Self {
+            severity: IssueSeverity::Warning,
+            message,
+        }
+    }
+
+    pub fn error(message: String) -> Self {
+        Self {
+            severity: IssueSeverity::Error,
+            message,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == IssueSeverity::Error
+    }
+}
+
+impl Policy {
+    pub fn validate(&self, connection: ConnectionMode) -> Vec<PolicyIssue> {
+        let mut issues = Vec::new();
+        let grant = &self.capabilities;
+
+        if !Path::new(&self.sandbox.root_path).is_dir() {
+            if connection == ConnectionMode::Tcp {
+                issues.push(PolicyIssue::warning(format!(
+                    "Root path {} was not found on this machine; the remote daemon checks it when the sandbox starts",
+                    self.sandbox.root_path
+                )));
+            } else {
+                issues.push(PolicyIssue::error(format!(
+                    "Root path {} does not exist",
+                    self.sandbox.root_path
+                )));
+            }
         }
+        if let Some(dir) = self.sandbox.working_directory() {
+            if !dir.starts_with('/') {
+                issues.push(PolicyIssue::error(format!(
+                    "Working directory {} is not an absolute path",
+                    dir
+                )));
+            }
+        }
+        let mut destinations = HashSet::new();
+        for mount in &self.sandbox.mounts {
+            issues.extend(mount.validate());
+            if !destinations.insert(mount.destination.trim_end_matches('/')) {
+                issues.push(PolicyIssue::error(format!(
+                    "Mount destination {} is used more than once",
+                    mount.destination
+                )));
+            }
+        }
+        if let Some(hostname) = &self.sandbox.hostname {
+            if let Err(e) = validate_hostname(hostname) {
+                issues.push(PolicyIssue::error(e));
+            }
+        }
+        for denied in &grant.denied_paths {
+            if Path::new(&self.sandbox.root_path).starts_with(&denied.path) {
+                issues.push(PolicyIssue::error(format!(
+                    "Denied path {} contains the root path {}, so the sandbox cannot start",
+                    denied, self.sandbox.root_path
+                )));
+            }
+        }
+        for path in grant.allowed_paths.iter().chain(&grant.denied_paths) {
+            if !path.path.starts_with('/') {
+                issues.push(PolicyIssue::error(format!("Path {} is not absolute", path)));
+            }
+        }
+
+        let path_grants: Vec<_> = grant
+            .allowed_paths
+            .iter()
+            .map(|entry| (entry, grant.capabilities_for(&entry.path)))
+            .collect();
+        if !grant.filesystem.is_empty()
+            && path_grants
+                .iter()
+                .all(|(_, capabilities)| capabilities.is_empty())
+        {
+            if grant.allowed_paths.is_empty() {
+                issues.push(PolicyIssue::error(format!(
+                    "Filesystem access ({}) is enabled but no allowed paths are listed, so nothing would be granted. Add / to cover everything under the root",
+                    capability_list(&grant.filesystem)
+                )));
+            } else {
+                issues.push(PolicyIssue::error(format!(
+                    "Filesystem access ({}) is enabled but every allowed path overrides it with no access, so nothing would be granted. Enable a capability on at least one allowed path",
+                    capability_list(&grant.filesystem)
+                )));
+            }
+        }
+        for (entry, capabilities) in &path_grants {
+            if capabilities.contains(&FilesystemCapability::Execute)
+                && !capabilities.contains(&FilesystemCapability::Read)
+            {
+                issues.push(PolicyIssue::warning(format!(
+                    "Execute access without read access on {} usually prevents programs from loading",
+                    entry
+                )));
+            }
+        }
+        for entry in &grant.outbound_allowlist {
+            if let Err(e) = validate_outbound_entry(entry) {
+                issues.push(PolicyIssue::error(format!(
+                    "Outbound allowlist entry {}",
+                    e
+                )));
+            }
+        }
+        if !grant.outbound_allowlist.is_empty() {
+            if grant.network.allows_outbound() {
+                issues.push(PolicyIssue::error(
+                    "Outbound allowlist is not enforced by hopsd, so the sandbox could reach any host. Remove the allowlist entries or set network access to Disabled or Loopback"
+                        .to_string(),
+                ));
+            } else {
+                issues.push(PolicyIssue::warning(format!(
+                    "Outbound allowlist is ignored while network access is {}",
+                    grant.network.as_str()
+                )));
+            }
+        }
+        for allowed in &grant.allowed_paths {
+            if let Some(denied) = grant
+                .denied_paths
+                .iter()
+                .find(|denied| Path::new(&allowed.path).starts_with(&denied.path))
+            {
+                issues.push(PolicyIssue::warning(format!(
+                    "Allowed path {} is inside denied path {} and will be unreachable",
+                    allowed, denied
+                )));
+            }
+        }
+
+        issues
+    }
+
+    pub fn diff(&self, other: &Policy) -> Vec<FieldDiff> {
+        let mut diffs = Vec::new();
+        let ours = &self.capabilities;
+        let theirs = &other.capabilities;
+
+        diff_value(
+            &mut diffs,
+            "description",
+            self.description.as_deref().unwrap_or("none"),
+            other.description.as_deref().unwrap_or("none"),
+        );
+        diff_value(
+            &mut diffs,
+            "network",
+            ours.network.as_str(),
+            theirs.network.as_str(),
+        );
+        diff_value(
+            &mut diffs,
+            "dns",
+            &ours.dns_enabled().to_string(),
+            &theirs.dns_enabled().to_string(),
+        );
+        diff_set(
+            &mut diffs,
+            "filesystem",
+            capability_names(&ours.filesystem),
+            capability_names(&theirs.filesystem),
+        );
+        diff_set(
+            &mut diffs,
+            "allowed_paths",
+            path_names(&ours.allowed_paths),
+            path_names(&theirs.allowed_paths),
+        );
+        for entry in &ours.allowed_paths {
+            if let Some(other_entry) = theirs
+                .allowed_paths
+                .iter()
+                .find(|other_entry| other_entry.path == entry.path)
+            {
+                diff_value(
+                    &mut diffs,
+                    &format!("allowed_paths[{}]", entry.path),
+                    &capability_list(&ours.capabilities_for(&entry.path)),
+                    &capability_list(&theirs.capabilities_for(&entry.path)),
+                );
+                diff_value(
+                    &mut diffs,
+                    &format!("allowed_paths[{}].note", entry.path),
+                    entry.note.as_deref().unwrap_or("none"),
+                    other_entry.note.as_deref().unwrap_or("none"),
+                );
+            }
+        }
+        diff_set(
+            &mut diffs,
+            "denied_paths",
+            path_names(&ours.denied_paths),
+            path_names(&theirs.denied_paths),
+        );
+        diff_set(
+            &mut diffs,
+            "outbound_allowlist",
+            ours.outbound_allowlist.iter().cloned().collect(),
+            theirs.outbound_allowlist.iter().cloned().collect(),
+        );
+        diff_value(
+            &mut diffs,
+            "cpus",
+            &optional(ours.resource_limits.cpus),
+            &optional(theirs.resource_limits.cpus),
+        );
+        diff_value(
+            &mut diffs,
+            "memory_bytes",
+            &optional(ours.resource_limits.memory_bytes),
+            &optional(theirs.resource_limits.memory_bytes),
+        );
+        diff_value(
+            &mut diffs,
+            "max_processes",
+            &optional(ours.resource_limits.max_processes),
+            &optional(theirs.resource_limits.max_processes),
+        );
+        diff_value(
+            &mut diffs,
+            "root_path",
+            &self.sandbox.root_path,
+            &other.sandbox.root_path,
+        );
+        diff_value(
+            &mut diffs,
+            "working_directory",
+            self.sandbox.working_directory().unwrap_or("inherit"),
+            other.sandbox.working_directory().unwrap_or("inherit"),
+        );
+        diff_value(
+            &mut diffs,
+            "hostname",
+            self.sandbox.hostname.as_deref().unwrap_or("none"),
+            other.sandbox.hostname.as_deref().unwrap_or("none"),
+        );
+        diff_set(
+            &mut diffs,
+            "environment",
+            self.sandbox
+                .environment
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect(),
+            other
+                .sandbox
+                .environment
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect(),
+        );
+
+        diffs
+    }
+}
+
+fn diff_value(diffs: &mut Vec<FieldDiff>, field: &str, from: &str, to: &str) {
+    if from != to {
+        diffs.push(FieldDiff {
+            field: field.to_string(),
+            change: FieldChange::Changed {
+                from: from.to_string(),
+                to: to.to_string(),
+            },
+        });
+    }
+}
+
+fn diff_set(diffs: &mut Vec<FieldDiff>, field: &str, from: BTreeSet<String>, to: BTreeSet<String>) {
+    for removed in from.difference(&to) {
+        diffs.push(FieldDiff {
+            field: field.to_string(),
+            change: FieldChange::Removed(removed.clone()),
+        });
+    }
+    for added in to.difference(&from) {
+        diffs.push(FieldDiff {
+            field: field.to_string(),
+            change: FieldChange::Added(added.clone()),
+        });
+    }
+}
+
+fn capability_names(capabilities: &HashSet<FilesystemCapability>) -> BTreeSet<String> {
+    capabilities
+        .iter()
+        .map(|capability| format!("{:?}", capability).to_lowercase())
+        .collect()
+}
+
+fn path_names(entries: &[PathEntry]) -> BTreeSet<String> {
+    entries.iter().map(|entry| entry.path.clone()).collect()
+}
+
+fn capability_list(capabilities: &HashSet<FilesystemCapability>) -> String {
+    let names = capability_names(capabilities);
+    if names.is_empty() {
+        "none".to_string()
+    } else {
+        names.into_iter().collect::<Vec<_>>().join(", ")
+    }
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "unset".to_string(), |v| v.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::capability::NetworkCapability;
+
+    fn policy_with_network(network: NetworkCapability) -> Policy {
+        let mut policy = Policy::default();
+        policy.capabilities.network = network;
+        policy.capabilities.outbound_allowlist = vec!["api.github.com:443".to_string()];
+        policy
+    }
+
+    #[test]
+    fn outbound_allowlist_blocks_runs_with_outbound_network() {
+        let issues =
+            policy_with_network(NetworkCapability::Outbound).validate(ConnectionMode::UnixSocket);
+
+        assert!(issues
+            .iter()
+            .any(|issue| issue.is_error() && issue.message.contains("not enforced by hopsd")));
+    }
+
+    #[test]
+    fn outbound_allowlist_only_warns_without_outbound_network() {
+        let issues =
+            policy_with_network(NetworkCapability::Disabled).validate(ConnectionMode::UnixSocket);
+
+        assert!(issues.iter().all(|issue| !issue.is_error()));
+        assert!(issues
+            .iter()
+            .any(|issue| issue.message.contains("allowlist is ignored")));
+    }
+
+    fn policy_with_paths(filesystem: &[FilesystemCapability], allowed_paths: &[&str]) -> Policy {
+        let mut policy = Policy::default();
+        policy.sandbox.root_path = "/".to_string();
+        policy.capabilities.filesystem = filesystem.iter().copied().collect();
+        policy.capabilities.allowed_paths = allowed_paths
+            .iter()
+            .map(|path| PathEntry::new(*path))
+            .collect();
+        policy
+    }
+
+    fn messages(issues: &[PolicyIssue]) -> Vec<&str> {
+        issues.iter().map(|issue| issue.message.as_str()).collect()
+    }
+
+    #[test]
+    fn read_access_without_allowed_paths_is_an_error() {
+        let issues = policy_with_paths(&[FilesystemCapability::Read], &[])
+            .validate(ConnectionMode::UnixSocket);
+
+        assert!(
+            issues
+                .iter()
+                .any(|issue| issue.is_error()
+                    && issue.message.contains("no allowed paths are listed"))
+        );
+    }
+
+    #[test]
+    fn path_overrides_without_access_are_an_error() {
+        let mut policy = policy_with_paths(&[FilesystemCapability::Read], &["/tmp"]);
+        policy
+            .capabilities
+            .path_capabilities
+            .insert("/tmp".to_string(), HashSet::new());
+
+        let issues = policy.validate(ConnectionMode::UnixSocket);
+
+        assert!(
+            issues
+                .iter()
+                .any(|issue| issue.is_error()
+                    && issue.message.contains("overrides it with no access"))
+        );
+    }
+
+    #[test]
+    fn path_override_grants_access_without_global_capabilities() {
+        let mut policy = policy_with_paths(&[], &["/tmp"]);
+        policy.capabilities.path_capabilities.insert(
+            "/tmp".to_string(),
+            HashSet::from([FilesystemCapability::Read]),
+        );
+
+        assert!(
+            policy.validate(ConnectionMode::UnixSocket).is_empty(),
+            "{:?}",
+            messages(&policy.validate(ConnectionMode::UnixSocket))
+        );
+    }
+
+    #[test]
+    fn execute_without_read_warns_per_path() {
+        let mut policy = policy_with_paths(&[FilesystemCapability::Read], &["/tmp", "/usr"]);
+        policy.capabilities.path_capabilities.insert(
+            "/usr".to_string(),
+            HashSet::from([FilesystemCapability::Execute]),
+        );
+
+        let issues = policy.validate(ConnectionMode::UnixSocket);
+
+        assert_eq!(
+            messages(&issues),
+            vec![
+                "Execute access without read access on /usr usually prevents programs from loading"
+            ]
+        );
+        assert!(!issues[0].is_error());
+    }
+
+    #[test]
+    fn missing_root_and_relative_paths_are_errors() {
+        let mut policy = policy_with_paths(&[FilesystemCapability::Read], &["tmp"]);
+        policy.sandbox.root_path = "/nonexistent/hops-root".to_string();
+
+        let issues = policy.validate(ConnectionMode::UnixSocket);
+
+        assert!(issues
+            .iter()
+            .any(|issue| issue.is_error() && issue.message.contains("does not exist")));
+        assert!(issues
+            .iter()
+            .any(|issue| issue.is_error() && issue.message == "Path tmp is not absolute"));
+    }
+
+    #[test]
+    fn missing_root_only_warns_for_remote_daemons() {
+        let mut policy = policy_with_paths(&[FilesystemCapability::Read], &["/tmp"]);
+        policy.sandbox.root_path = "/nonexistent/hops-root".to_string();
+
+        let issues = policy.validate(ConnectionMode::Tcp);
+
+        assert!(issues.iter().all(|issue| !issue.is_error()));
+        assert!(issues
+            .iter()
+            .any(|issue| issue.message.contains("not found on this machine")));
+    }
+
+    #[test]
+    fn denied_root_is_an_error() {
+        let mut policy = policy_with_paths(&[FilesystemCapability::Read], &["/tmp"]);
+        policy.capabilities.denied_paths = vec![PathEntry::new("/")];
+
+        let issues = policy.validate(ConnectionMode::UnixSocket);
+
+        assert!(
+            issues
+                .iter()
+                .any(|issue| issue.is_error()
+                    && issue.message.contains("so the sandbox cannot start"))
+        );
+        assert!(issues
+            .iter()
+            .any(|issue| !issue.is_error() && issue.message.contains("will be unreachable")));
+    }
+
+    #[test]
+    fn relative_working_directory_is_an_error() {
+        let mut policy = policy_with_paths(&[FilesystemCapability::Read], &["/tmp"]);
+        policy.sandbox.working_directory = "src".to_string();
+
+        let issues = policy.validate(ConnectionMode::UnixSocket);
+
+        assert!(issues
+            .iter()
+            .any(|issue| issue.is_error() && issue.message.contains("is not an absolute path")));
+    }
+
+    #[test]
+    fn invalid_outbound_entry_is_an_error() {
+        let mut policy = policy_with_network(NetworkCapability::Disabled);
+        policy.capabilities.outbound_allowlist = vec!["not a host".to_string()];
+
+        let issues = policy.validate(ConnectionMode::UnixSocket);
+
+        assert!(
+            issues
+                .iter()
+                .any(|issue| issue.is_error()
+                    && issue.message.starts_with("Outbound allowlist entry"))
+        );
     }
 }
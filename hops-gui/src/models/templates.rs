@@ -0,0 +1,149 @@
+use crate::models::capability::{
+    CapabilityGrant, FilesystemCapability, NetworkCapability, PathEntry, ResourceLimits,
+    SandboxConfig,
+};
+use crate::models::policy::Policy;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ProfileTemplate {
+    pub slug: &'static str,
+    pub name: &'static str,
+    pub icon: &'static str,
+    pub description: &'static str,
+    build: fn() -> Policy,
+}
+
+impl ProfileTemplate {
+    pub const ALL: &'static [ProfileTemplate] = &[
+        ProfileTemplate {
+            slug: "isolated-build",
+            name: "Network-isolated build",
+            icon: "🔒",
+            description: "Compile and test code with no network access",
+            build: network_isolated_build,
+        },
+        ProfileTemplate {
+            slug: "read-only-analysis",
+            name: "Read-only analysis",
+            icon: "🔍",
+            description: "Inspect files without modifying them or reaching the network",
+            build: read_only_analysis,
+        },
+        ProfileTemplate {
+            slug: "full-trusted",
+            name: "Full trusted",
+            icon: "🔓",
+            description: "Unrestricted access for trusted tools",
+            build: full_trusted,
+        },
+    ];
+
+    pub fn policy(&self) -> Policy {
+        let mut policy = (self.build)();
+        policy.name = self.slug.to_string();
+        policy.description = Some(self.description.to_string());
+        policy
+    }
+
+    pub fn summary(&self) -> String {
+        let capabilities = (self.build)().capabilities;
+        let filesystem: Vec<&str> = FilesystemCapability::ALL
+            .iter()
+            .filter(|capability| capabilities.filesystem.contains(capability))
+            .map(FilesystemCapability::as_str)
+            .collect();
+        format!(
+            "Network: {} · Filesystem: {} · {} allowed path{}",
+            capabilities.network,
+            if filesystem.is_empty() {
+                "none".to_string()
+            } else {
+                filesystem.join(", ")
+            },
+            capabilities.allowed_paths.len(),
+            if capabilities.allowed_paths.len() == 1 {
+                ""
+            } else {
+                "s"
+            }
+        )
+    }
+}
+
+fn network_isolated_build() -> Policy {
+    Policy {
+        capabilities: CapabilityGrant {
+            network: NetworkCapability::Disabled,
+            dns: None,
+            filesystem: [
+                FilesystemCapability::Read,
+                FilesystemCapability::Write,
+                FilesystemCapability::Execute,
+            ]
+            .into_iter()
+            .collect(),
+            allowed_paths: vec![PathEntry::new("/usr"), PathEntry::new("/tmp")],
+            denied_paths: vec![PathEntry::new("/etc/shadow"), PathEntry::new("/root/.ssh")],
+            path_capabilities: BTreeMap::new(),
+            outbound_allowlist: vec![],
+            resource_limits: ResourceLimits {
+                cpus: Some(4),
+                memory_bytes: Some(4 * 1024 * 1024 * 1024),
+                max_processes: Some(512),
+                extra: BTreeMap::new(),
+            },
+            extra: BTreeMap::new(),
+        },
+        sandbox: SandboxConfig {
+            working_directory: "/tmp".to_string(),
+            ..SandboxConfig::default()
+        },
+        ..Policy::default()
+    }
+}
+
+fn read_only_analysis() -> Policy {
+    Policy {
+        capabilities: CapabilityGrant {
+            network: NetworkCapability::Disabled,
+            dns: None,
+            filesystem: [FilesystemCapability::Read].into_iter().collect(),
+            allowed_paths: vec![PathEntry::new("/usr")],
+            denied_paths: vec![PathEntry::new("/etc/shadow"), PathEntry::new("/root/.ssh")],
+            path_capabilities: BTreeMap::new(),
+            outbound_allowlist: vec![],
+            resource_limits: ResourceLimits {
+                cpus: Some(2),
+                memory_bytes: Some(1024 * 1024 * 1024),
+                max_processes: Some(64),
+                extra: BTreeMap::new(),
+            },
+            extra: BTreeMap::new(),
+        },
+        ..Policy::default()
+    }
+}
+
+fn full_trusted() -> Policy {
+    Policy {
+        capabilities: CapabilityGrant {
+            network: NetworkCapability::Full,
+            dns: None,
+            filesystem: [
+                FilesystemCapability::Read,
+                FilesystemCapability::Write,
+                FilesystemCapability::Execute,
+            ]
+            .into_iter()
+            .collect(),
+            allowed_paths: vec![PathEntry::new("/")],
+            denied_paths: vec![],
+            path_capabilities: BTreeMap::new(),
+            outbound_allowlist: vec![],
+            resource_limits: ResourceLimits::default(),
+            extra: BTreeMap::new(),
+        },
+        ..Policy::default()
+    }
+}
@@ -0,0 +1,138 @@
+use iced::widget::text;
+use iced::Color;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AppTheme {
+    Light,
+    Dark,
+    #[default]
+    System,
+}
+
+impl AppTheme {
+    pub const ALL: [AppTheme; 3] = [AppTheme::System, AppTheme::Light, AppTheme::Dark];
+
+    pub fn next(self) -> Self {
+        match self {
+            AppTheme::System => AppTheme::Light,
+            AppTheme::Light => AppTheme::Dark,
+            AppTheme::Dark => AppTheme::System,
+        }
+    }
+
+    pub fn resolve(self) -> iced::Theme {
+        let is_dark = match self {
+            AppTheme::Light => false,
+            AppTheme::Dark => true,
+            AppTheme::System => system_prefers_dark(),
+        };
+        if is_dark {
+            iced::Theme::Dark
+        } else {
+            iced::Theme::Light
+        }
+    }
+}
+
+impl std::fmt::Display for AppTheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AppTheme::Light => write!(f, "Light"),
+            AppTheme::Dark => write!(f, "Dark"),
+            AppTheme::System => write!(f, "System"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    pub sidebar: Color,
+    pub sidebar_border: Color,
+    pub surface: Color,
+    pub surface_raised: Color,
+    pub border: Color,
+    pub shadow: Color,
+    pub text: Color,
+    pub text_muted: Color,
+    pub nav_inactive: Color,
+    pub nav_inactive_hover: Color,
+    pub nav_border: Color,
+    pub neutral: Color,
+    pub neutral_hover: Color,
+    pub neutral_border: Color,
+    pub track: Color,
+    pub track_border: Color,
+    pub overlay: Color,
+}
+
+pub const DARK: Palette = Palette {
+    sidebar: Color::from_rgb(0.12, 0.12, 0.12),
+    sidebar_border: Color::from_rgb(0.25, 0.25, 0.25),
+    surface: Color::from_rgb(0.16, 0.16, 0.18),
+    surface_raised: Color::from_rgb(0.25, 0.25, 0.3),
+    border: Color::from_rgb(0.35, 0.35, 0.4),
+    shadow: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+    text: Color::from_rgb(0.9, 0.9, 0.95),
+    text_muted: Color::from_rgb(0.7, 0.7, 0.7),
+    nav_inactive: Color::from_rgb(0.18, 0.18, 0.2),
+    nav_inactive_hover: Color::from_rgb(0.22, 0.22, 0.25),
+    nav_border: Color::from_rgb(0.35, 0.35, 0.4),
+    neutral: Color::from_rgb(0.4, 0.4, 0.45),
+    neutral_hover: Color::from_rgb(0.45, 0.45, 0.5),
+    neutral_border: Color::from_rgb(0.5, 0.5, 0.55),
+    track: Color::from_rgb(0.2, 0.2, 0.2),
+    track_border: Color::from_rgb(0.4, 0.4, 0.4),
+    overlay: Color::from_rgba(0.0, 0.0, 0.0, 0.6),
+};
+
+pub const LIGHT: Palette = Palette {
+    sidebar: Color::from_rgb(0.92, 0.92, 0.94),
+    sidebar_border: Color::from_rgb(0.8, 0.8, 0.82),
+    surface: Color::from_rgb(0.98, 0.98, 0.99),
+    surface_raised: Color::from_rgb(0.88, 0.88, 0.92),
+    border: Color::from_rgb(0.8, 0.8, 0.84),
+    shadow: Color::from_rgba(0.0, 0.0, 0.0, 0.12),
+    text: Color::from_rgb(0.12, 0.12, 0.15),
+    text_muted: Color::from_rgb(0.35, 0.35, 0.4),
+    nav_inactive: Color::from_rgb(0.86, 0.86, 0.89),
+    nav_inactive_hover: Color::from_rgb(0.8, 0.8, 0.84),
+    nav_border: Color::from_rgb(0.75, 0.75, 0.8),
+    neutral: Color::from_rgb(0.55, 0.55, 0.6),
+    neutral_hover: Color::from_rgb(0.5, 0.5, 0.55),
+    neutral_border: Color::from_rgb(0.45, 0.45, 0.5),
+    track: Color::from_rgb(0.85, 0.85, 0.87),
+    track_border: Color::from_rgb(0.7, 0.7, 0.72),
+    overlay: Color::from_rgba(0.0, 0.0, 0.0, 0.35),
+};
+
+pub fn palette(theme: &iced::Theme) -> &'static Palette {
+    if theme.extended_palette().is_dark {
+        &DARK
+    } else {
+        &LIGHT
+    }
+}
+
+pub fn muted_text(theme: &iced::Theme) -> text::Style {
+    text::Style {
+        color: Some(palette(theme).text_muted),
+    }
+}
+
+fn system_prefers_dark() -> bool {
+    static PREFERS_DARK: OnceLock<bool> = OnceLock::new();
+    *PREFERS_DARK.get_or_init(|| {
+        if cfg!(target_os = "macos") {
+            std::process::Command::new("defaults")
+                .args(["read", "-g", "AppleInterfaceStyle"])
+                .output()
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim() == "Dark")
+                .unwrap_or(true)
+        } else {
+            true
+        }
+    })
+}
@@ -1,7 +1,13 @@
 use crate::models::policy::Policy;
+use std::collections::HashMap;
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MAX_PROFILE_BACKUPS: usize = 5;
+const MAX_FILENAME_BYTES: usize = 255;
+const PORTABILITY_RESERVED_CHARS: [char; 7] = [':', '*', '?', '"', '<', '>', '|'];
 
 pub fn get_profiles_dir() -> io::Result<PathBuf> {
     let home = dirs::home_dir()
@@ -31,6 +37,8 @@ pub fn load_profiles() -> io::Result<Vec<Policy>> {
                             .and_then(|s| s.to_str())
                             .unwrap_or("unnamed")
                             .to_string();
+                        policy.modified = entry.metadata().and_then(|m| m.modified()).ok();
+                        policy.capabilities.normalize();
                         profiles.push(policy);
                     }
                 }
@@ -41,13 +49,342 @@ pub fn load_profiles() -> io::Result<Vec<Policy>> {
     Ok(profiles)
 }
 
+pub fn validate_profile_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Name cannot be empty".to_string());
+    }
+    if name == "." || name == ".." {
+        return Err("Name cannot be \".\" or \"..\"".to_string());
+    }
+    if name.contains(['/', '\\']) {
+        return Err("Name cannot contain path separators".to_string());
+    }
+    if name.chars().any(char::is_control) {
+        return Err("Name cannot contain control characters".to_string());
+    }
+    if name.len() + ".toml".len() > MAX_FILENAME_BYTES {
+        return Err(format!(
+            "Name is too long for a filename ({} bytes max)",
+            MAX_FILENAME_BYTES - ".toml".len()
+        ));
+    }
+    Ok(())
+}
+
+pub fn profile_name_warnings(name: &str) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let reserved: Vec<char> = name
+        .chars()
+        .filter(|c| PORTABILITY_RESERVED_CHARS.contains(c))
+        .collect();
+    if !reserved.is_empty() {
+        warnings.push(format!(
+            "{} cannot be used in filenames on Windows",
+            reserved
+                .iter()
+                .map(|c| format!("\"{}\"", c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    if name.starts_with('.') {
+        warnings.push("A leading dot hides the file in most file browsers".to_string());
+    }
+    if name != name.trim() {
+        warnings.push("Leading or trailing spaces are easy to miss in the filename".to_string());
+    } else if name.contains(' ') {
+        warnings
+            .push("Spaces must be quoted when passing --profile on the command line".to_string());
+    }
+    warnings
+}
+
+pub fn validate_environment(environment: &HashMap<String, String>) -> Result<(), String> {
+    let mut invalid: Vec<&str> = environment
+        .keys()
+        .map(String::as_str)
+        .filter(|key| !is_valid_environment_key(key))
+        .collect();
+    if invalid.is_empty() {
+        return Ok(());
+    }
+    invalid.sort_unstable();
+    Err(format!(
+        "Invalid environment variable name{}: {} (use letters, digits and underscores, not starting with a digit)",
+        if invalid.len() == 1 { "" } else { "s" },
+        invalid.join(", ")
+    ))
+}
+
+fn is_valid_environment_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+pub fn parse_env_file(content: &str) -> Result<Vec<(String, String)>, String> {
+    let mut entries = Vec::new();
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").map_or(line, str::trim_start);
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("Line {}: expected KEY=VALUE", number + 1))?;
+        let key = key.trim();
+        if !is_valid_environment_key(key) {
+            return Err(format!(
+                "Line {}: invalid variable name \"{}\"",
+                number + 1,
+                key
+            ));
+        }
+        let value = parse_env_value(value.trim())
+            .ok_or_else(|| format!("Line {}: unterminated quoted value", number + 1))?;
+        entries.push((key.to_string(), value));
+    }
+    Ok(entries)
+}
+
+fn parse_env_value(value: &str) -> Option<String> {
+    if let Some(rest) = value.strip_prefix('"') {
+        let mut parsed = String::new();
+        let mut chars = rest.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => return Some(parsed),
+                '\\' => match chars.next()? {
+                    'n' => parsed.push('\n'),
+                    't' => parsed.push('\t'),
+                    other => parsed.push(other),
+                },
+                _ => parsed.push(c),
+            }
+        }
+        return None;
+    }
+    if let Some(rest) = value.strip_prefix('\'') {
+        return rest.find('\'').map(|end| rest[..end].to_string());
+    }
+    let value = value.find(" #").map_or(value, |comment| &value[..comment]);
+    Some(value.trim_end().to_string())
+}
+
+fn profile_path(name: &str) -> io::Result<PathBuf> {
+    validate_profile_name(name).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    Ok(get_profiles_dir()?.join(format!("{}.toml", name)))
+}
+
 pub fn save_profile(name: &str, policy: &Policy) -> io::Result<()> {
-    let profiles_dir = get_profiles_dir()?;
-    let file_path = profiles_dir.join(format!("{}.toml", name));
+    let file_path = profile_path(name)?;
 
-    let toml_content = toml::to_string_pretty(policy)
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let toml_content = profile_to_toml(policy)?;
+
+    if let Ok(previous) = fs::read_to_string(&file_path) {
+        if previous != toml_content {
+            backup_profile(name, &previous)?;
+        }
+    }
 
     fs::write(file_path, toml_content)?;
     Ok(())
 }
+
+fn backups_dir(name: &str) -> io::Result<PathBuf> {
+    validate_profile_name(name).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    Ok(get_profiles_dir()?.join(".backups").join(name))
+}
+
+fn backup_profile(name: &str, content: &str) -> io::Result<()> {
+    let dir = backups_dir(name)?;
+    fs::create_dir_all(&dir)?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    fs::write(dir.join(format!("{}.toml", timestamp)), content)?;
+
+    for stale in list_backups(name)?.into_iter().skip(MAX_PROFILE_BACKUPS) {
+        fs::remove_file(stale)?;
+    }
+    Ok(())
+}
+
+pub fn list_backups(name: &str) -> io::Result<Vec<PathBuf>> {
+    let dir = backups_dir(name)?;
+    let mut backups: Vec<(u128, PathBuf)> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter_map(|path| {
+                let timestamp = path.file_stem()?.to_str()?.parse().ok()?;
+                Some((timestamp, path))
+            })
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e),
+    };
+    backups.sort_by_key(|(timestamp, _)| std::cmp::Reverse(*timestamp));
+    Ok(backups.into_iter().map(|(_, path)| path).collect())
+}
+
+pub fn load_latest_backup(name: &str) -> io::Result<Option<Policy>> {
+    let Some(path) = list_backups(name)?.into_iter().next() else {
+        return Ok(None);
+    };
+    let content = fs::read_to_string(&path)?;
+    let mut policy = toml::from_str::<Policy>(&content)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    policy.name = name.to_string();
+    policy.modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+    policy.capabilities.normalize();
+    Ok(Some(policy))
+}
+
+pub fn profile_to_toml(policy: &Policy) -> io::Result<String> {
+    toml::to_string_pretty(policy).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+pub fn read_profile_file(name: &str) -> io::Result<Option<String>> {
+    match fs::read_to_string(profile_path(name)?) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn restore_profile(name: &str, content: &str) -> io::Result<Policy> {
+    let file_path = profile_path(name)?;
+    let mut policy = toml::from_str::<Policy>(content)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::write(&file_path, content)?;
+    policy.name = name.to_string();
+    policy.modified = fs::metadata(&file_path).and_then(|m| m.modified()).ok();
+    policy.capabilities.normalize();
+    Ok(policy)
+}
+
+pub fn delete_profile(name: &str) -> io::Result<()> {
+    let file_path = profile_path(name)?;
+
+    if file_path.exists() {
+        fs::remove_file(file_path)?;
+    }
+    Ok(())
+}
+
+pub fn profile_to_json(policy: &Policy) -> io::Result<String> {
+    let value = profile_json_value(policy)?;
+    serde_json::to_string_pretty(&value).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+pub fn profiles_to_json_archive(policies: &[Policy]) -> io::Result<String> {
+    let values = policies
+        .iter()
+        .map(profile_json_value)
+        .collect::<io::Result<Vec<_>>>()?;
+    serde_json::to_string_pretty(&values).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn profile_json_value(policy: &Policy) -> io::Result<serde_json::Value> {
+    let mut value =
+        serde_json::to_value(policy).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    if let serde_json::Value::Object(fields) = &mut value {
+        fields.insert(
+            "name".to_string(),
+            serde_json::Value::String(policy.name.clone()),
+        );
+    }
+
+    Ok(value)
+}
+
+pub fn parse_profile_archive(content: &str) -> Result<Vec<Policy>, String> {
+    let values: Vec<serde_json::Value> =
+        serde_json::from_str(content).map_err(|e| format!("Not a profile archive: {}", e))?;
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let name = value
+                .get("name")
+                .and_then(|n| n.as_str())
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("imported-{}", index + 1));
+            let mut policy: Policy = serde_json::from_value(value)
+                .map_err(|e| format!("Profile \"{}\" is invalid: {}", name, e))?;
+            validate_environment(&policy.sandbox.environment)
+                .map_err(|e| format!("Profile \"{}\": {}", name, e))?;
+            policy.name = name;
+            policy.capabilities.normalize();
+            Ok(policy)
+        })
+        .collect()
+}
+
+pub fn policy_schema_json() -> io::Result<String> {
+    serde_json::to_string_pretty(&schemars::schema_for!(Policy))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+pub fn import_profile(path: &Path) -> io::Result<Policy> {
+    let content = fs::read_to_string(path)?;
+    let is_json = path.extension().and_then(|s| s.to_str()) == Some("json");
+
+    let mut policy = if is_json {
+        serde_json::from_str::<Policy>(&content)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+    } else {
+        toml::from_str::<Policy>(&content)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+    };
+
+    policy.name = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("imported")
+        .to_string();
+    validate_environment(&policy.sandbox.environment)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    policy.capabilities.normalize();
+
+    Ok(policy)
+}
+
+pub fn parse_profile_text(content: &str) -> Result<Policy, String> {
+    if let Ok(mut policy) = toml::from_str::<Policy>(content) {
+        if let Some(name) = content
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix('#'))
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        {
+            policy.name = name.to_string();
+        }
+        validate_environment(&policy.sandbox.environment)?;
+        policy.capabilities.normalize();
+        return Ok(policy);
+    }
+
+    let value: serde_json::Value = serde_json::from_str(content)
+        .map_err(|_| "Clipboard does not contain a TOML or JSON profile".to_string())?;
+    let name = value
+        .get("name")
+        .and_then(|n| n.as_str())
+        .map(str::to_string);
+    let mut policy: Policy = serde_json::from_value(value)
+        .map_err(|e| format!("Clipboard JSON is not a valid profile: {}", e))?;
+    if let Some(name) = name {
+        policy.name = name;
+    }
+    validate_environment(&policy.sandbox.environment)?;
+    policy.capabilities.normalize();
+    Ok(policy)
+}
@@ -0,0 +1,40 @@
+use crate::app::RunRecord;
+
+const CSV_HEADER: [&str; 7] = [
+    "id",
+    "profile",
+    "start_time",
+    "duration",
+    "exit_code",
+    "pid",
+    "denied_capabilities",
+];
+
+pub fn records_to_csv(records: &[RunRecord]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push('\n');
+
+    for record in records {
+        let fields = [
+            escape_csv_field(&record.id),
+            escape_csv_field(&record.profile_name),
+            escape_csv_field(&record.start_time),
+            escape_csv_field(&record.duration),
+            record.exit_code.to_string(),
+            record.pid.to_string(),
+            escape_csv_field(&record.denied_capabilities.join("; ")),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -1 +1,3 @@
 pub mod config;
+pub mod history;
+pub mod settings;
@@ -0,0 +1,134 @@
+use crate::app::ProfileSortOrder;
+use crate::theme::AppTheme;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionMode {
+    #[default]
+    UnixSocket,
+    Tcp,
+}
+
+impl ConnectionMode {
+    pub const ALL: [ConnectionMode; 2] = [ConnectionMode::UnixSocket, ConnectionMode::Tcp];
+}
+
+impl std::fmt::Display for ConnectionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConnectionMode::UnixSocket => write!(f, "Unix socket"),
+            ConnectionMode::Tcp => write!(f, "TCP (http://host:port)"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SandboxLaunch {
+    pub profile: String,
+    pub command: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub width: f32,
+    pub height: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y: Option<f32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub theme: AppTheme,
+    pub profile_sort: ProfileSortOrder,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_group_by: Option<String>,
+    pub history_auto_refresh: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_refresh_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_run_command: Option<String>,
+    pub connection_mode: ConnectionMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub socket_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tcp_address: Option<String>,
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    pub hidden_sandbox_ids: BTreeSet<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_processes_limit: Option<u32>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub recent_commands: BTreeMap<String, Vec<String>>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub sandbox_launches: BTreeMap<String, SandboxLaunch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<WindowGeometry>,
+}
+
+pub const DEFAULT_MAX_PROCESSES_LIMIT: u32 = 65536;
+pub const MAX_RECENT_COMMANDS: usize = 10;
+pub const DEFAULT_HISTORY_REFRESH_SECS: u64 = 5;
+
+impl Settings {
+    pub fn max_processes_limit(&self) -> u32 {
+        self.max_processes_limit
+            .unwrap_or(DEFAULT_MAX_PROCESSES_LIMIT)
+    }
+
+    pub fn history_refresh_secs(&self) -> u64 {
+        self.history_refresh_secs
+            .unwrap_or(DEFAULT_HISTORY_REFRESH_SECS)
+    }
+
+    pub fn recent_commands_for(&self, profile_name: &str) -> &[String] {
+        self.recent_commands
+            .get(profile_name)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    pub fn remember_command(&mut self, profile_name: &str, command: &str) {
+        let commands = self
+            .recent_commands
+            .entry(profile_name.to_string())
+            .or_default();
+        commands.retain(|c| c != command);
+        commands.insert(0, command.to_string());
+        commands.truncate(MAX_RECENT_COMMANDS);
+    }
+}
+
+pub fn get_settings_path() -> io::Result<PathBuf> {
+    let home = dirs::home_dir()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not find home directory"))?;
+
+    Ok(home.join(".hops").join("gui-settings.toml"))
+}
+
+pub fn load_settings() -> Settings {
+    get_settings_path()
+        .and_then(fs::read_to_string)
+        .ok()
+        .and_then(|content| toml::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_settings(settings: &Settings) -> io::Result<()> {
+    let settings_path = get_settings_path()?;
+
+    if let Some(parent) = settings_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let toml_content = toml::to_string_pretty(settings)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    fs::write(settings_path, toml_content)?;
+    Ok(())
+}
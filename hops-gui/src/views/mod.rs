@@ -1,3 +1,7 @@
+pub mod modal;
+pub mod notification;
+pub mod profile_compare;
 pub mod profile_editor;
 pub mod profile_list;
 pub mod run_history;
+pub mod settings;
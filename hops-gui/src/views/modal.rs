@@ -0,0 +1,40 @@
+use crate::app::Message;
+use crate::theme;
+use iced::widget::{center, container, mouse_area, opaque, stack};
+use iced::{Border, Element, Length};
+
+pub fn view<'a>(
+    base: Element<'a, Message>,
+    content: Element<'a, Message>,
+    on_blur: Message,
+) -> Element<'a, Message> {
+    let dialog = container(content)
+        .width(Length::Fixed(420.0))
+        .padding(24)
+        .style(|theme| container::Style {
+            background: Some(iced::Background::Color(theme::palette(theme).surface)),
+            border: Border {
+                color: theme::palette(theme).border,
+                width: 1.0,
+                radius: 8.0.into(),
+            },
+            shadow: iced::Shadow {
+                color: theme::palette(theme).shadow,
+                offset: iced::Vector::new(0.0, 6.0),
+                blur_radius: 18.0,
+            },
+            ..Default::default()
+        });
+
+    stack![
+        base,
+        opaque(
+            mouse_area(center(opaque(dialog)).style(|theme| container::Style {
+                background: Some(iced::Background::Color(theme::palette(theme).overlay)),
+                ..Default::default()
+            }))
+            .on_press(on_blur)
+        )
+    ]
+    .into()
+}
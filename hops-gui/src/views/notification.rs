@@ -0,0 +1,104 @@
+use crate::app::{Message, Notification};
+use crate::theme;
+use iced::widget::{button, container, row, text};
+use iced::{Border, Color, Element, Length};
+
+pub fn view(notification: &Notification) -> Element<'_, Message> {
+    let (background, border_color) = if notification.is_error {
+        (
+            Color::from_rgb(0.6, 0.15, 0.15),
+            Color::from_rgb(0.8, 0.3, 0.3),
+        )
+    } else {
+        (
+            Color::from_rgb(0.15, 0.4, 0.15),
+            Color::from_rgb(0.3, 0.6, 0.3),
+        )
+    };
+
+    container(
+        row![
+            text(if notification.is_error { "⚠" } else { "✓" })
+                .size(14)
+                .color(Color::WHITE),
+            text(&notification.message)
+                .size(13)
+                .color(Color::from_rgb(1.0, 0.95, 0.95))
+                .width(Length::Fill),
+            button(text("×").size(16))
+                .on_press(Message::DismissNotification)
+                .padding([2, 10])
+                .style(|_theme, _status| button::Style {
+                    background: None,
+                    text_color: Color::WHITE,
+                    ..Default::default()
+                }),
+        ]
+        .spacing(10)
+        .align_y(iced::alignment::Vertical::Center),
+    )
+    .width(Length::Fill)
+    .padding([10, 16])
+    .style(move |_theme| container::Style {
+        background: Some(iced::Background::Color(background)),
+        border: Border {
+            color: border_color,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        ..Default::default()
+    })
+    .into()
+}
+
+pub fn banner(message: &str) -> Element<'_, Message> {
+    container(
+        row![
+            text("⚠").size(14).color(Color::WHITE),
+            text(message)
+                .size(13)
+                .color(Color::from_rgb(1.0, 0.95, 0.95))
+                .width(Length::Fill),
+        ]
+        .spacing(10)
+        .align_y(iced::alignment::Vertical::Center),
+    )
+    .width(Length::Fill)
+    .padding([10, 16])
+    .style(|_theme| container::Style {
+        background: Some(iced::Background::Color(Color::from_rgb(0.55, 0.35, 0.05))),
+        border: Border {
+            color: Color::from_rgb(0.8, 0.55, 0.1),
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        ..Default::default()
+    })
+    .into()
+}
+
+pub fn progress<'a>(message: impl text::IntoFragment<'a>) -> Element<'a, Message> {
+    container(
+        row![
+            text("⏳").size(14),
+            text(message).size(13).width(Length::Fill),
+        ]
+        .spacing(10)
+        .align_y(iced::alignment::Vertical::Center),
+    )
+    .width(Length::Fill)
+    .padding([10, 16])
+    .style(|theme| container::Style {
+        background: Some(iced::Background::Color(
+            theme::palette(theme).surface_raised,
+        )),
+        text_color: Some(theme::palette(theme).text),
+        border: Border {
+            color: theme::palette(theme).border,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        ..Default::default()
+    })
+    .into()
+}
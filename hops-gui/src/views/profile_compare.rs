@@ -0,0 +1,116 @@
+use crate::app::Message;
+use crate::models::policy::{FieldChange, Policy};
+use crate::theme;
+use iced::widget::{column, container, pick_list, row, scrollable, text, Column};
+use iced::{Border, Color, Element, Length};
+
+pub fn view<'a>(
+    profiles: &'a [Policy],
+    left: Option<&'a str>,
+    right: Option<&'a str>,
+) -> Element<'a, Message> {
+    let title = text("COMPARE PROFILES").size(32);
+
+    let names: Vec<String> = profiles.iter().map(|p| p.name.clone()).collect();
+    let selectors = row![
+        pick_list(
+            names.clone(),
+            left.map(str::to_string),
+            Message::CompareLeftSelected
+        )
+        .placeholder("Base profile…")
+        .padding(10)
+        .width(Length::Fill),
+        text("→").size(20),
+        pick_list(
+            names,
+            right.map(str::to_string),
+            Message::CompareRightSelected
+        )
+        .placeholder("Compare against…")
+        .padding(10)
+        .width(Length::Fill),
+    ]
+    .spacing(15)
+    .align_y(iced::alignment::Vertical::Center);
+
+    let find = |name: Option<&str>| name.and_then(|name| profiles.iter().find(|p| p.name == name));
+
+    let body: Element<'a, Message> = match (find(left), find(right)) {
+        (Some(base), Some(other)) => {
+            let diffs = base.diff(other);
+            if diffs.is_empty() {
+                text("These profiles are identical.")
+                    .size(14)
+                    .style(theme::muted_text)
+                    .into()
+            } else {
+                let rows = diffs
+                    .into_iter()
+                    .fold(Column::new().spacing(8), |col, diff| {
+                        let (symbol, value, color) = match diff.change {
+                            FieldChange::Added(value) => {
+                                ("+", value, Color::from_rgb(0.3, 0.75, 0.3))
+                            }
+                            FieldChange::Removed(value) => {
+                                ("−", value, Color::from_rgb(0.9, 0.35, 0.35))
+                            }
+                            FieldChange::Changed { from, to } => (
+                                "~",
+                                format!("{} → {}", from, to),
+                                Color::from_rgb(0.95, 0.7, 0.2),
+                            ),
+                        };
+                        col.push(
+                            container(
+                                row![
+                                    text(symbol)
+                                        .size(14)
+                                        .color(color)
+                                        .width(Length::Fixed(20.0)),
+                                    text(diff.field)
+                                        .size(13)
+                                        .style(theme::muted_text)
+                                        .width(Length::Fixed(220.0)),
+                                    text(value).size(13).color(color).width(Length::Fill),
+                                ]
+                                .spacing(10),
+                            )
+                            .padding([8, 12])
+                            .width(Length::Fill)
+                            .style(move |theme| container::Style {
+                                background: Some(iced::Background::Color(
+                                    theme::palette(theme).surface,
+                                )),
+                                border: Border {
+                                    color,
+                                    width: 1.0,
+                                    radius: 4.0.into(),
+                                },
+                                ..Default::default()
+                            }),
+                        )
+                    });
+                column![
+                    text("Differences going from the base profile to the compared one")
+                        .size(12)
+                        .style(theme::muted_text),
+                    rows,
+                ]
+                .spacing(10)
+                .into()
+            }
+        }
+        _ => text("Select two profiles to compare their capabilities, paths and limits.")
+            .size(14)
+            .style(theme::muted_text)
+            .into(),
+    };
+
+    let content = column![title, selectors, body].spacing(30).padding(30);
+
+    container(scrollable(content))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
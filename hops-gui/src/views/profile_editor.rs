@@ -1,34 +1,80 @@
-use crate::app::{MemoryUnit, Message, PathInputs, PathType, ValidationErrors};
-use crate::models::capability::{FilesystemCapability, NetworkCapability};
-use crate::models::policy::Policy;
+use crate::app::{
+    format_memory_value, ExternalChangeChoice, MemoryUnit, Message, MoveDirection, PathInputs,
+    PathType, SaveState, UnsavedChangesChoice, ValidationErrors,
+};
+use crate::models::capability::{
+    CapabilityGrant, FilesystemCapability, NetworkCapability, PathEntry, HOSTNAME_HELP,
+    MAX_HOSTNAME_LEN,
+};
+use crate::models::policy::{Policy, PolicyIssue};
+use crate::theme;
+use crate::utils::config;
 use iced::widget::{
     button, checkbox, column, container, pick_list, progress_bar, row, scrollable, slider, text,
     text_input, tooltip, Column,
 };
-use iced::{Border, Color, Element, Length};
+use iced::{Border, Color, Element, Length, Task};
+use std::collections::HashSet;
 
-const NETWORK_OPTIONS: &[NetworkCapability] = &[
-    NetworkCapability::Disabled,
-    NetworkCapability::Loopback,
-    NetworkCapability::Outbound,
-    NetworkCapability::Full,
+const MAX_MEMORY_GB: f64 = 32.0;
+const EDITOR_SCROLLABLE_ID: &str = "profile-editor";
+
+const FIELD_LABELS: &[(&str, &str, Option<&str>, f32)] = &[
+    ("name", "Profile name", None, 0.0),
+    (
+        "outbound_allowlist",
+        "Outbound allowlist",
+        Some("network"),
+        0.15,
+    ),
+    ("Allowed_path", "Allowed paths", Some("allowed_paths"), 0.35),
+    ("Denied_path", "Denied paths", Some("denied_paths"), 0.5),
+    ("memory_bytes", "Memory limit", Some("resources"), 0.7),
+    ("max_processes", "Max processes", Some("resources"), 0.75),
+    ("root_path", "Root path", Some("root_path"), 0.85),
+    ("hostname", "Hostname", Some("hostname"), 0.92),
 ];
 
+#[allow(clippy::too_many_arguments)]
 pub fn view<'a>(
     policy: &'a Policy,
     path_inputs: &'a PathInputs,
     validation_errors: &'a ValidationErrors,
     memory_unit: &'a MemoryUnit,
     memory_display_value: &'a str,
+    host_cores: u32,
+    save_state: SaveState,
+    collapsed: &'a HashSet<String>,
 ) -> Element<'a, Message> {
-    let title = text(format!("PROFILE: {}", policy.name.to_uppercase())).size(32);
+    let dirty = save_state != SaveState::Clean;
+    let title = text(format!(
+        "PROFILE: {}{}",
+        policy.name.to_uppercase(),
+        if dirty { " •" } else { "" }
+    ))
+    .size(32);
 
     let name_section = column![
         text("Profile Name").size(14),
         text_input("Enter profile name", &policy.name)
+            .id(field_input_id("name"))
             .on_input(Message::NameChanged)
             .padding(10)
             .width(Length::Fill),
+        text(format!("Saved as {}.toml", policy.name))
+            .size(12)
+            .style(theme::muted_text),
+        config::profile_name_warnings(&policy.name)
+            .into_iter()
+            .fold(Column::new().spacing(4), |col, warning| {
+                col.push(
+                    row![
+                        text("⚠").size(12).color(Color::from_rgb(1.0, 0.7, 0.0)),
+                        text(warning).size(12).color(Color::from_rgb(1.0, 0.7, 0.0))
+                    ]
+                    .spacing(8),
+                )
+            }),
         if let Some(error) = validation_errors.fields.get("name") {
             container(
                 row![
@@ -53,84 +99,182 @@ pub fn view<'a>(
     ]
     .spacing(8);
 
-    let network_display: Vec<String> = NETWORK_OPTIONS.iter().map(|c| format!("{:?}", c)).collect();
-    let current_display = format!("{:?}", policy.capabilities.network);
-
     let network_section = column![
-        text("NETWORK CAPABILITY").size(14),
         tooltip(
-            pick_list(network_display, Some(current_display), |selected| {
-                let capability = match selected.as_str() {
-                    "Loopback" => NetworkCapability::Loopback,
-                    "Outbound" => NetworkCapability::Outbound,
-                    "Full" => NetworkCapability::Full,
-                    _ => NetworkCapability::Disabled,
-                };
-                Message::NetworkCapabilityChanged(capability)
-            })
+            pick_list(
+                NetworkCapability::ALL,
+                Some(policy.capabilities.network),
+                Message::NetworkCapabilityChanged
+            )
             .padding(10)
             .width(Length::Fill),
             "Disabled: No network • Loopback: localhost only • Outbound: Can connect out • Full: Bidirectional access",
             tooltip::Position::Top
         ),
-        text(match policy.capabilities.network {
-            NetworkCapability::Disabled => "All network access blocked",
-            NetworkCapability::Loopback => "Only localhost connections allowed",
-            NetworkCapability::Outbound => "Outbound connections allowed",
-            NetworkCapability::Full => "Full network access",
-        })
-        .size(12)
-        .color(Color::from_rgb(0.6, 0.6, 0.6))
+        text(policy.capabilities.network.help_text())
+            .size(12)
+        .color(Color::from_rgb(0.6, 0.6, 0.6)),
+        tooltip(
+            checkbox(
+                if policy.capabilities.dns.is_some() {
+                    "Allow DNS resolution"
+                } else {
+                    "Allow DNS resolution (default for this network level)"
+                },
+                policy.capabilities.dns_enabled()
+            )
+            .on_toggle(Message::DnsToggled),
+            "Lets the sandbox resolve host names independently of the network level. Resets to the level's default when the level changes",
+            tooltip::Position::Top
+        )
     ]
     .spacing(8);
 
-    let filesystem_checkboxes = column![
-        text("FILESYSTEM PERMISSIONS").size(14),
-        checkbox(
-            "Read",
-            policy
-                .capabilities
-                .filesystem
-                .contains(&FilesystemCapability::Read)
-        )
-        .on_toggle(|_| Message::FilesystemCapabilityToggled(FilesystemCapability::Read)),
-        checkbox(
-            "Write",
-            policy
-                .capabilities
-                .filesystem
-                .contains(&FilesystemCapability::Write)
-        )
-        .on_toggle(|_| Message::FilesystemCapabilityToggled(FilesystemCapability::Write)),
-        checkbox(
-            "Execute",
-            policy
-                .capabilities
-                .filesystem
-                .contains(&FilesystemCapability::Execute)
-        )
-        .on_toggle(|_| Message::FilesystemCapabilityToggled(FilesystemCapability::Execute)),
-    ]
-    .spacing(10);
+    let outbound_section: Element<'a, Message> = if policy.capabilities.network.allows_outbound() {
+        let entry_list = policy
+            .capabilities
+            .outbound_allowlist
+            .iter()
+            .enumerate()
+            .fold(Column::new().spacing(8), |col, (idx, entry)| {
+                col.push(
+                    row![
+                        text(entry).width(Length::Fill),
+                        button(text("×").size(16))
+                            .on_press(Message::RemoveOutboundEntry(idx))
+                            .padding(8)
+                            .style(|_theme, _status| button::Style {
+                                background: Some(iced::Background::Color(Color::from_rgb(
+                                    0.8, 0.2, 0.2,
+                                ))),
+                                text_color: Color::WHITE,
+                                border: Border {
+                                    color: Color::from_rgb(0.9, 0.3, 0.3),
+                                    width: 1.0,
+                                    radius: 2.0.into(),
+                                },
+                                ..Default::default()
+                            }),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                )
+            });
+
+        column![
+            text("OUTBOUND ALLOWLIST").size(14),
+            text(if policy.capabilities.outbound_allowlist.is_empty() {
+                "No restrictions: outbound connections may reach any host"
+            } else {
+                "hopsd does not enforce the allowlist yet, so runs are blocked until these entries are removed"
+            })
+            .size(12)
+            .style(theme::muted_text),
+            entry_list,
+            row![
+                text_input(
+                    "host:port or CIDR, e.g. api.github.com:443 10.0.0.0/8",
+                    &path_inputs.outbound_input
+                )
+                .id(field_input_id("outbound_allowlist"))
+                .on_input(Message::OutboundEntryInputChanged)
+                .on_submit(Message::AddOutboundEntries)
+                .padding(10)
+                .width(Length::Fill),
+                button(text("+").size(20))
+                    .on_press(Message::AddOutboundEntries)
+                    .padding([8, 16])
+                    .style(|_theme, _status| button::Style {
+                        background: Some(iced::Background::Color(Color::from_rgb(0.2, 0.5, 0.8))),
+                        text_color: Color::WHITE,
+                        border: Border {
+                            color: Color::from_rgb(0.3, 0.6, 0.9),
+                            width: 1.0,
+                            radius: 2.0.into(),
+                        },
+                        ..Default::default()
+                    }),
+            ]
+            .spacing(10),
+            if let Some(error) = validation_errors.fields.get("outbound_allowlist") {
+                container(
+                    row![
+                        text("⚠").size(14).color(Color::from_rgb(1.0, 0.7, 0.0)),
+                        text(error).size(12).color(Color::from_rgb(1.0, 0.95, 0.95))
+                    ]
+                    .spacing(8)
+                    .padding(8),
+                )
+                .style(|_theme| container::Style {
+                    background: Some(iced::Background::Color(Color::from_rgb(0.6, 0.15, 0.15))),
+                    border: Border {
+                        color: Color::from_rgb(0.8, 0.3, 0.3),
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    ..Default::default()
+                })
+            } else {
+                container(text(""))
+            },
+        ]
+        .spacing(8)
+        .into()
+    } else {
+        Column::new().into()
+    };
+
+    let filesystem_checkboxes = FilesystemCapability::ALL.iter().fold(
+        column![
+            text("Applied to newly added paths; adjust each allowed path below")
+                .size(12)
+                .style(theme::muted_text),
+        ]
+        .spacing(10),
+        |col, capability| {
+            let capability = *capability;
+            col.push(tooltip(
+                checkbox(
+                    capability.label(),
+                    policy.capabilities.filesystem.contains(&capability),
+                )
+                .on_toggle(move |_| Message::FilesystemCapabilityToggled(capability)),
+                text(format!(
+                    "{}. {}",
+                    capability.help_text(),
+                    FilesystemCapability::SCOPE_HELP
+                )),
+                tooltip::Position::Top,
+            ))
+        },
+    );
 
     let allowed_paths_section = build_path_section(
-        "ALLOWED PATHS",
         &policy.capabilities.allowed_paths,
         &path_inputs.allowed_input,
+        &path_inputs.allowed_filter,
         PathType::Allowed,
+        Some(&policy.capabilities),
         validation_errors,
     );
 
     let denied_paths_section = build_path_section(
-        "DENIED PATHS",
         &policy.capabilities.denied_paths,
         &path_inputs.denied_input,
+        &path_inputs.denied_filter,
         PathType::Denied,
+        None,
         validation_errors,
     );
 
     let cpu_value = policy.capabilities.resource_limits.cpus.unwrap_or(2);
-    let cpu_slider = slider(1.0..=16.0, cpu_value as f32, Message::CpuChanged).width(Length::Fill);
+    let cpu_max = host_cores as f32;
+    let cpu_slider = slider(
+        1.0..=cpu_max,
+        (cpu_value as f32).min(cpu_max),
+        Message::CpuChanged,
+    )
+    .width(Length::Fill);
 
     let max_processes_value = policy
         .capabilities
@@ -139,16 +283,33 @@ pub fn view<'a>(
         .map(|p| p.to_string())
         .unwrap_or_default();
 
+    let memory_bytes = policy.capabilities.resource_limits.memory_bytes;
+    let memory_gb = memory_bytes.map_or(0.0, |bytes| MemoryUnit::GB.from_bytes(bytes));
+    let memory_label = match memory_bytes {
+        Some(bytes) => format!(
+            "{} {}",
+            format_memory_value(bytes, *memory_unit),
+            memory_unit
+        ),
+        None => "Unlimited".to_string(),
+    };
+    let memory_slider = slider(
+        0.0..=MAX_MEMORY_GB as f32,
+        memory_gb.min(MAX_MEMORY_GB) as f32,
+        Message::MemorySliderChanged,
+    )
+    .step(0.25)
+    .width(Length::Fill);
+
     let memory_unit_options: Vec<String> =
         MemoryUnit::all().iter().map(|u| u.to_string()).collect();
     let current_unit = memory_unit.to_string();
 
     let resources_section = column![
-        text("RESOURCE LIMITS").size(18),
         column![
             row![
                 text("CPU Cores:").width(Length::Fixed(140.0)),
-                text(format!("{} / 16", cpu_value)).width(Length::Fixed(80.0))
+                text(format!("{} / {}", cpu_value, host_cores)).width(Length::Fixed(80.0))
             ]
             .spacing(10),
             tooltip(
@@ -156,13 +317,13 @@ pub fn view<'a>(
                 "Number of CPU cores allocated to the sandbox. More cores = better performance but higher resource usage",
                 tooltip::Position::Top
             ),
-            progress_bar(0.0..=16.0, cpu_value as f32)
+            progress_bar(0.0..=cpu_max, cpu_value as f32)
                 .height(8)
-                .style(|_theme| progress_bar::Style {
-                    background: iced::Background::Color(Color::from_rgb(0.2, 0.2, 0.2)),
+                .style(|theme| progress_bar::Style {
+                    background: iced::Background::Color(theme::palette(theme).track),
                     bar: iced::Background::Color(Color::from_rgb(0.3, 0.6, 0.9)),
                     border: Border {
-                        color: Color::from_rgb(0.4, 0.4, 0.4),
+                        color: theme::palette(theme).track_border,
                         width: 1.0,
                         radius: 2.0.into(),
                     },
@@ -170,10 +331,20 @@ pub fn view<'a>(
         ]
         .spacing(8),
         column![
-            text("Memory").size(14),
+            row![
+                text("Memory:").width(Length::Fixed(140.0)),
+                text(memory_label).width(Length::Fixed(160.0))
+            ]
+            .spacing(10),
+            tooltip(
+                memory_slider,
+                "Drag to pick a common memory limit. Use the field below for an exact value",
+                tooltip::Position::Top
+            ),
             tooltip(
                 row![
                     text_input("e.g., 512", memory_display_value)
+                        .id(field_input_id("memory_bytes"))
                         .on_input(Message::MemoryBytesChanged)
                         .padding(10)
                         .width(Length::FillPortion(3)),
@@ -194,16 +365,16 @@ pub fn view<'a>(
             ),
             {
                 if let Some(bytes) = policy.capabilities.resource_limits.memory_bytes {
-                    let max_bytes = 32.0 * 1024.0 * 1024.0 * 1024.0;
+                    let max_bytes = MemoryUnit::GB.to_bytes(MAX_MEMORY_GB) as f64;
                     let percentage = (bytes as f64 / max_bytes * 100.0).min(100.0);
                     column![
                         progress_bar(0.0..=100.0, percentage as f32)
                             .height(8)
-                            .style(|_theme| progress_bar::Style {
-                                background: iced::Background::Color(Color::from_rgb(0.2, 0.2, 0.2)),
+                            .style(|theme| progress_bar::Style {
+                                background: iced::Background::Color(theme::palette(theme).track),
                                 bar: iced::Background::Color(Color::from_rgb(0.2, 0.7, 0.4)),
                                 border: Border {
-                                    color: Color::from_rgb(0.4, 0.4, 0.4),
+                                    color: theme::palette(theme).track_border,
                                     width: 1.0,
                                     radius: 2.0.into(),
                                 },
@@ -244,6 +415,7 @@ pub fn view<'a>(
             text("Max Processes").size(14),
             tooltip(
                 text_input("Maximum number of processes", &max_processes_value)
+                    .id(field_input_id("max_processes"))
                     .on_input(Message::MaxProcessesChanged)
                     .padding(10)
                     .width(Length::Fill),
@@ -284,16 +456,31 @@ pub fn view<'a>(
 
     let save_button = tooltip(
         button(
-            text("💾 SAVE PROFILE")
-                .width(Length::Fill)
-                .align_x(iced::alignment::Horizontal::Center),
+            text(match save_state {
+                SaveState::Saving => "⏳ SAVING…",
+                SaveState::Dirty => "💾 SAVE PROFILE *",
+                SaveState::Clean => "💾 SAVE PROFILE",
+            })
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
         )
-        .on_press(Message::SaveProfile)
+        .on_press_maybe((save_state != SaveState::Saving).then_some(Message::SaveProfile))
         .width(Length::Fill)
         .padding(14)
-        .style(|_theme, status| {
-            let base_color = Color::from_rgb(0.2, 0.6, 0.2);
-            let hover_color = Color::from_rgb(0.25, 0.65, 0.25);
+        .style(move |_theme, status| {
+            let (base_color, hover_color, border_color) = if dirty {
+                (
+                    Color::from_rgb(0.8, 0.5, 0.1),
+                    Color::from_rgb(0.85, 0.55, 0.15),
+                    Color::from_rgb(0.9, 0.6, 0.2),
+                )
+            } else {
+                (
+                    Color::from_rgb(0.2, 0.6, 0.2),
+                    Color::from_rgb(0.25, 0.65, 0.25),
+                    Color::from_rgb(0.3, 0.7, 0.3),
+                )
+            };
             button::Style {
                 background: Some(iced::Background::Color(
                     if matches!(status, button::Status::Hovered) {
@@ -304,13 +491,16 @@ pub fn view<'a>(
                 )),
                 text_color: Color::WHITE,
                 border: Border {
-                    color: Color::from_rgb(0.3, 0.7, 0.3),
+                    color: border_color,
                     width: 1.0,
                     radius: 6.0.into(),
                 },
                 shadow: if matches!(status, button::Status::Hovered) {
                     iced::Shadow {
-                        color: Color::from_rgba(0.2, 0.6, 0.2, 0.4),
+                        color: Color {
+                            a: 0.4,
+                            ..base_color
+                        },
                         offset: iced::Vector::new(0.0, 2.0),
                         blur_radius: 10.0,
                     }
@@ -332,9 +522,10 @@ pub fn view<'a>(
     .on_press(Message::SwitchView(crate::app::ViewMode::ProfileList))
     .width(Length::Fill)
     .padding(14)
-    .style(|_theme, status| {
-        let base_color = Color::from_rgb(0.4, 0.4, 0.45);
-        let hover_color = Color::from_rgb(0.45, 0.45, 0.5);
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
         button::Style {
             background: Some(iced::Background::Color(
                 if matches!(status, button::Status::Hovered) {
@@ -345,13 +536,16 @@ pub fn view<'a>(
             )),
             text_color: Color::WHITE,
             border: Border {
-                color: Color::from_rgb(0.5, 0.5, 0.55),
+                color: palette.neutral_border,
                 width: 1.0,
                 radius: 6.0.into(),
             },
             shadow: if matches!(status, button::Status::Hovered) {
                 iced::Shadow {
-                    color: Color::from_rgba(0.4, 0.4, 0.45, 0.4),
+                    color: Color {
+                        a: 0.4,
+                        ..palette.neutral
+                    },
                     offset: iced::Vector::new(0.0, 2.0),
                     blur_radius: 8.0,
                 }
@@ -362,40 +556,749 @@ pub fn view<'a>(
         }
     });
 
+    let export_json_button = button(text("⇪ Export JSON").size(14))
+        .on_press(Message::ExportProfileJson)
+        .padding([8, 14])
+        .style(|theme, status| {
+            let palette = theme::palette(theme);
+            let base_color = palette.neutral;
+            let hover_color = palette.neutral_hover;
+            button::Style {
+                background: Some(iced::Background::Color(
+                    if matches!(status, button::Status::Hovered) {
+                        hover_color
+                    } else {
+                        base_color
+                    },
+                )),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: palette.neutral_border,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }
+        });
+
+    let copy_toml_button = button(text("⎘ Copy TOML").size(14))
+        .on_press(Message::CopyProfileToml)
+        .padding([8, 14])
+        .style(|theme, status| {
+            let palette = theme::palette(theme);
+            let base_color = palette.neutral;
+            let hover_color = palette.neutral_hover;
+            button::Style {
+                background: Some(iced::Background::Color(
+                    if matches!(status, button::Status::Hovered) {
+                        hover_color
+                    } else {
+                        base_color
+                    },
+                )),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: palette.neutral_border,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }
+        });
+
+    let duplicate_button = button(text("⧉ Duplicate").size(14))
+        .on_press(Message::DuplicateSelectedProfile)
+        .padding([8, 14])
+        .style(|theme, status| {
+            let palette = theme::palette(theme);
+            let base_color = palette.neutral;
+            let hover_color = palette.neutral_hover;
+            button::Style {
+                background: Some(iced::Background::Color(
+                    if matches!(status, button::Status::Hovered) {
+                        hover_color
+                    } else {
+                        base_color
+                    },
+                )),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: palette.neutral_border,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }
+        });
+
+    let restore_backup_button = button(text("↶ Restore backup").size(14))
+        .on_press(Message::RestoreProfileBackup)
+        .padding([8, 14])
+        .style(|theme, status| {
+            let palette = theme::palette(theme);
+            let base_color = palette.neutral;
+            let hover_color = palette.neutral_hover;
+            button::Style {
+                background: Some(iced::Background::Color(
+                    if matches!(status, button::Status::Hovered) {
+                        hover_color
+                    } else {
+                        base_color
+                    },
+                )),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: palette.neutral_border,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }
+        });
+
+    let preview_button = button(text("⌕ Preview").size(14))
+        .on_press(Message::ShowPolicyPreview)
+        .padding([8, 14])
+        .style(|theme, status| {
+            let palette = theme::palette(theme);
+            let base_color = palette.neutral;
+            let hover_color = palette.neutral_hover;
+            button::Style {
+                background: Some(iced::Background::Color(
+                    if matches!(status, button::Status::Hovered) {
+                        hover_color
+                    } else {
+                        base_color
+                    },
+                )),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: palette.neutral_border,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }
+        });
+
+    let duplicate_hint = if cfg!(target_os = "macos") {
+        "Duplicate this profile and edit the copy (⌘D)"
+    } else {
+        "Duplicate this profile and edit the copy (Ctrl+D)"
+    };
+
+    let share_actions = row![
+        tooltip(duplicate_button, duplicate_hint, tooltip::Position::Bottom),
+        copy_toml_button,
+        export_json_button,
+        restore_backup_button,
+        preview_button
+    ]
+    .spacing(10);
+
+    let import_env_button = button(text("⇩ Import .env").size(14))
+        .on_press(Message::ImportEnvFile)
+        .padding([8, 14])
+        .style(|theme, status| {
+            let palette = theme::palette(theme);
+            let base_color = palette.neutral;
+            let hover_color = palette.neutral_hover;
+            button::Style {
+                background: Some(iced::Background::Color(
+                    if matches!(status, button::Status::Hovered) {
+                        hover_color
+                    } else {
+                        base_color
+                    },
+                )),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: palette.neutral_border,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }
+        });
+
+    let mut environment: Vec<_> = policy.sandbox.environment.iter().collect();
+    environment.sort();
+    let environment_list = if environment.is_empty() {
+        column![text("No environment variables")
+            .size(12)
+            .style(theme::muted_text)]
+    } else {
+        environment
+            .into_iter()
+            .fold(Column::new().spacing(6), |col, (key, value)| {
+                col.push(text(format!("{}={}", key, value)).size(14))
+            })
+    };
+
+    let root_path_section = column![
+        row![
+            tooltip(
+                text_input("/", &policy.sandbox.root_path)
+                    .id(field_input_id("root_path"))
+                    .on_input(Message::RootPathChanged)
+                    .padding(10)
+                    .width(Length::Fill),
+                "Directory the sandbox filesystem is built from",
+                tooltip::Position::Top
+            ),
+            button(text("Browse…").size(14))
+                .on_press(Message::BrowseRootPath)
+                .padding([10, 14])
+                .style(|theme, status| {
+                    let palette = theme::palette(theme);
+                    let base_color = palette.neutral;
+                    let hover_color = palette.neutral_hover;
+                    button::Style {
+                        background: Some(iced::Background::Color(
+                            if matches!(status, button::Status::Hovered) {
+                                hover_color
+                            } else {
+                                base_color
+                            },
+                        )),
+                        text_color: Color::WHITE,
+                        border: Border {
+                            color: palette.neutral_border,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                }),
+        ]
+        .spacing(10),
+        if let Some(error) = validation_errors.fields.get("root_path") {
+            container(
+                row![
+                    text("⚠").size(14).color(Color::from_rgb(1.0, 0.7, 0.0)),
+                    text(error).size(12).color(Color::from_rgb(1.0, 0.95, 0.95))
+                ]
+                .spacing(8)
+                .padding(8),
+            )
+            .style(|_theme| container::Style {
+                background: Some(iced::Background::Color(Color::from_rgb(0.6, 0.15, 0.15))),
+                border: Border {
+                    color: Color::from_rgb(0.8, 0.3, 0.3),
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            })
+        } else {
+            container(text(""))
+        }
+    ]
+    .spacing(8);
+
+    let working_directory_section = column![tooltip(
+        text_input(
+            "Inherit from the daemon (leave empty)",
+            &policy.sandbox.working_directory
+        )
+        .on_input(Message::WorkingDirectoryChanged)
+        .padding(10)
+        .width(Length::Fill),
+        "Absolute path the command starts in. Leave empty to let the daemon choose",
+        tooltip::Position::Top
+    ),]
+    .spacing(8);
+
+    let hostname = policy.sandbox.hostname.as_deref().unwrap_or("");
+    let hostname_section = column![
+        row![
+            tooltip(
+                text_input("Daemon default (leave empty)", hostname)
+                    .id(field_input_id("hostname"))
+                    .on_input(Message::HostnameChanged)
+                    .padding(10)
+                    .width(Length::Fill),
+                HOSTNAME_HELP,
+                tooltip::Position::Top
+            ),
+            text(format!("{}/{}", hostname.len(), MAX_HOSTNAME_LEN))
+                .size(12)
+                .color(if hostname.len() > MAX_HOSTNAME_LEN {
+                    Color::from_rgb(0.9, 0.35, 0.35)
+                } else {
+                    Color::from_rgb(0.5, 0.5, 0.5)
+                }),
+        ]
+        .spacing(10)
+        .align_y(iced::Alignment::Center),
+        if let Some(error) = validation_errors.fields.get("hostname") {
+            container(
+                row![
+                    text("⚠").size(14).color(Color::from_rgb(1.0, 0.7, 0.0)),
+                    text(error).size(12).color(Color::from_rgb(1.0, 0.95, 0.95))
+                ]
+                .spacing(8)
+                .padding(8),
+            )
+            .style(|_theme| container::Style {
+                background: Some(iced::Background::Color(Color::from_rgb(0.6, 0.15, 0.15))),
+                border: Border {
+                    color: Color::from_rgb(0.8, 0.3, 0.3),
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            })
+        } else {
+            container(text(""))
+        }
+    ]
+    .spacing(8);
+
+    let environment_section = column![import_env_button, environment_list].spacing(10);
+
     let content = column![
         title,
+        share_actions,
         name_section,
-        network_section,
-        filesystem_checkboxes,
-        allowed_paths_section,
-        denied_paths_section,
-        resources_section,
+        collapsible_section(
+            "network",
+            "NETWORK CAPABILITY",
+            14,
+            collapsed,
+            column![network_section, outbound_section].spacing(30)
+        ),
+        collapsible_section(
+            "filesystem",
+            "DEFAULT FILESYSTEM PERMISSIONS",
+            14,
+            collapsed,
+            filesystem_checkboxes
+        ),
+        collapsible_section(
+            "allowed_paths",
+            "ALLOWED PATHS",
+            14,
+            collapsed,
+            allowed_paths_section
+        ),
+        collapsible_section(
+            "denied_paths",
+            "DENIED PATHS",
+            14,
+            collapsed,
+            denied_paths_section
+        ),
+        collapsible_section(
+            "resources",
+            "RESOURCE LIMITS",
+            18,
+            collapsed,
+            resources_section
+        ),
+        collapsible_section("root_path", "ROOT PATH", 14, collapsed, root_path_section),
+        collapsible_section(
+            "working_directory",
+            "WORKING DIRECTORY",
+            14,
+            collapsed,
+            working_directory_section
+        ),
+        collapsible_section("hostname", "HOSTNAME", 14, collapsed, hostname_section),
+        collapsible_section(
+            "environment",
+            "ENVIRONMENT",
+            18,
+            collapsed,
+            environment_section
+        ),
         row![back_button, save_button].spacing(10),
     ]
     .spacing(30)
     .padding(30);
 
-    container(scrollable(content))
+    container(scrollable(content).id(scrollable::Id::new(EDITOR_SCROLLABLE_ID)))
         .width(Length::Fill)
         .height(Length::Fill)
         .into()
 }
 
-fn build_path_section<'a>(
+pub fn field_input_id(field: &str) -> text_input::Id {
+    text_input::Id::new(format!("profile-field-{}", field))
+}
+
+pub fn field_section(field: &str) -> Option<&'static str> {
+    FIELD_LABELS
+        .iter()
+        .find(|(key, _, _, _)| *key == field)
+        .and_then(|(_, _, section, _)| *section)
+}
+
+fn collapsible_section<'a>(
+    key: &'static str,
     title: &'a str,
-    paths: &'a [String],
+    size: u16,
+    collapsed: &HashSet<String>,
+    body: impl Into<Element<'a, Message>>,
+) -> Element<'a, Message> {
+    let is_collapsed = collapsed.contains(key);
+    let header = button(
+        text(format!(
+            "{} {}",
+            if is_collapsed { "▸" } else { "▾" },
+            title
+        ))
+        .size(size),
+    )
+    .on_press(Message::ToggleSection(key.to_string()))
+    .padding(0)
+    .style(|theme, _status| button::Style {
+        background: None,
+        text_color: theme::palette(theme).text,
+        ..Default::default()
+    });
+    if is_collapsed {
+        header.into()
+    } else {
+        column![header, body.into()].spacing(10).into()
+    }
+}
+
+pub fn scroll_to_field<T: Send + 'static>(field: &str) -> Task<T> {
+    let offset = FIELD_LABELS
+        .iter()
+        .find(|(key, _, _, _)| *key == field)
+        .map_or(0.0, |(_, _, _, offset)| *offset);
+    Task::batch([
+        scrollable::snap_to(
+            scrollable::Id::new(EDITOR_SCROLLABLE_ID),
+            scrollable::RelativeOffset { x: 0.0, y: offset },
+        ),
+        text_input::focus(field_input_id(field)),
+    ])
+}
+
+pub fn validation_summary(
+    validation_errors: &ValidationErrors,
+    flash: bool,
+) -> Element<'_, Message> {
+    if validation_errors.fields.is_empty() {
+        return Column::new().into();
+    }
+
+    let mut errors: Vec<(&String, &String)> = validation_errors.fields.iter().collect();
+    errors.sort_by_key(|(field, _)| {
+        FIELD_LABELS
+            .iter()
+            .position(|(key, _, _, _)| key == field)
+            .unwrap_or(FIELD_LABELS.len())
+    });
+
+    let error_list = errors
+        .into_iter()
+        .fold(Column::new().spacing(4), |col, (field, error)| {
+            let label = FIELD_LABELS
+                .iter()
+                .find(|(key, _, _, _)| key == field)
+                .map_or(field.as_str(), |(_, label, _, _)| label);
+            col.push(
+                button(
+                    row![
+                        text(format!("{} →", label))
+                            .size(12)
+                            .color(Color::from_rgb(1.0, 0.85, 0.4)),
+                        text(error).size(12).color(Color::from_rgb(1.0, 0.95, 0.95))
+                    ]
+                    .spacing(8),
+                )
+                .on_press(Message::FocusValidationField(field.clone()))
+                .padding([2, 4])
+                .style(|_theme, status| button::Style {
+                    background: match status {
+                        button::Status::Hovered => Some(iced::Background::Color(Color::from_rgba(
+                            1.0, 1.0, 1.0, 0.08,
+                        ))),
+                        _ => None,
+                    },
+                    text_color: Color::WHITE,
+                    ..Default::default()
+                }),
+            )
+        });
+
+    let count = validation_errors.fields.len();
+    let banner = container(
+        column![
+            row![
+                text("⚠").size(14).color(Color::from_rgb(1.0, 0.7, 0.0)),
+                text(format!(
+                    "{} {} must be fixed before saving",
+                    count,
+                    if count == 1 { "problem" } else { "problems" }
+                ))
+                .size(14)
+                .color(Color::WHITE)
+            ]
+            .spacing(8),
+            error_list,
+        ]
+        .spacing(8)
+        .padding(12),
+    )
+    .width(Length::Fill)
+    .style(move |_theme| container::Style {
+        background: Some(iced::Background::Color(if flash {
+            Color::from_rgb(0.8, 0.25, 0.25)
+        } else {
+            Color::from_rgb(0.6, 0.15, 0.15)
+        })),
+        border: Border {
+            color: if flash {
+                Color::from_rgb(1.0, 0.7, 0.0)
+            } else {
+                Color::from_rgb(0.8, 0.3, 0.3)
+            },
+            width: if flash { 2.0 } else { 1.0 },
+            radius: 4.0.into(),
+        },
+        ..Default::default()
+    });
+
+    container(banner).padding([0, 30]).into()
+}
+
+pub fn policy_preview(preview: &str) -> Element<'_, Message> {
+    column![
+        text("POLICY PREVIEW").size(20),
+        text("Exactly what Run sends to the daemon for this profile")
+            .size(12)
+            .style(theme::muted_text),
+        scrollable(text(preview).size(12).font(iced::Font::MONOSPACE)).height(Length::Fixed(360.0)),
+        row![
+            dialog_button(
+                "Copy",
+                Message::CopyPolicyPreview,
+                Color::from_rgb(0.2, 0.4, 0.8)
+            ),
+            tooltip(
+                dialog_button(
+                    "Copy proto debug",
+                    Message::CopyProtoDebug,
+                    Color::from_rgb(0.35, 0.35, 0.5)
+                ),
+                "Copies the raw hops.Policy proto for bug reports",
+                tooltip::Position::Top
+            ),
+            dialog_button(
+                "Close",
+                Message::ClosePolicyPreview,
+                Color::from_rgb(0.4, 0.4, 0.45)
+            ),
+        ]
+        .spacing(10),
+    ]
+    .spacing(15)
+    .into()
+}
+
+pub fn run_bar<'a>(
+    profile_idx: usize,
+    command: &'a str,
+    command_error: Option<&'a str>,
+    launching: bool,
+    recent_commands: &'a [String],
+    issues: &'a [PolicyIssue],
+) -> Element<'a, Message> {
+    let blocked = launching || issues.iter().any(PolicyIssue::is_error);
+    let run_message = (!blocked).then(|| Message::RunSandbox {
+        profile_idx,
+        command: command.to_string(),
+    });
+
+    let run_label = if launching {
+        "⏳ Launching…"
+    } else {
+        "▶ Run"
+    };
+    let run_button = button(text(run_label).size(14))
+        .on_press_maybe(run_message.clone())
+        .padding([10, 18])
+        .style(|_theme, status| {
+            let base_color = Color::from_rgb(0.2, 0.6, 0.2);
+            let hover_color = Color::from_rgb(0.25, 0.65, 0.25);
+            button::Style {
+                background: Some(iced::Background::Color(match status {
+                    button::Status::Hovered => hover_color,
+                    button::Status::Disabled => Color::from_rgb(0.3, 0.35, 0.3),
+                    _ => base_color,
+                })),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: hover_color,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }
+        });
+
+    let command_input = text_input("Command to run, e.g. /bin/sh -c 'make test'", command)
+        .on_input(Message::RunCommandChanged)
+        .padding(10)
+        .width(Length::Fill);
+    let command_input = match run_message {
+        Some(message) => command_input.on_submit(message),
+        None => command_input,
+    };
+
+    let recent_picker = pick_list(recent_commands, None::<String>, Message::RunCommandChanged)
+        .placeholder("Recent commands…")
+        .padding(10)
+        .width(Length::Fixed(220.0));
+
+    let command_error = command_error.map(|error| {
+        row![
+            text("✗").size(12).color(Color::from_rgb(0.9, 0.35, 0.35)),
+            text(error).size(12).color(Color::from_rgb(0.9, 0.35, 0.35))
+        ]
+        .spacing(8)
+    });
+
+    let issue_list = issues.iter().fold(Column::new().spacing(4), |col, issue| {
+        let (icon, color) = if issue.is_error() {
+            ("✗", Color::from_rgb(0.9, 0.35, 0.35))
+        } else {
+            ("⚠", Color::from_rgb(1.0, 0.7, 0.0))
+        };
+        col.push(
+            row![
+                text(icon).size(12).color(color),
+                text(issue.message.as_str()).size(12).color(color)
+            ]
+            .spacing(8),
+        )
+    });
+
+    container(
+        Column::new()
+            .push(
+                row![command_input, recent_picker, run_button]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+            )
+            .push_maybe(command_error)
+            .push(issue_list)
+            .spacing(10),
+    )
+    .padding([20, 30])
+    .into()
+}
+
+fn build_path_section<'a>(
+    paths: &'a [PathEntry],
     input_value: &'a str,
+    filter: &'a str,
     path_type: PathType,
+    capabilities: Option<&'a CapabilityGrant>,
     validation_errors: &'a ValidationErrors,
 ) -> Element<'a, Message> {
-    let path_list: Column<Message> =
-        paths
-            .iter()
-            .enumerate()
-            .fold(Column::new().spacing(8), |col, (idx, path)| {
-                col.push(
+    let needle = filter.trim().to_lowercase();
+    let matches_filter = |entry: &PathEntry| {
+        needle.is_empty()
+            || entry.path.to_lowercase().contains(&needle)
+            || entry
+                .note
+                .as_deref()
+                .is_some_and(|note| note.to_lowercase().contains(&needle))
+    };
+    let shown = paths.iter().filter(|entry| matches_filter(entry)).count();
+    let path_list: Column<Message> = paths
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| matches_filter(entry))
+        .fold(Column::new().spacing(8), |col, (idx, entry)| {
+            let path = entry.path.as_str();
+            let path_capabilities = capabilities.map_or_else(
+                || row![],
+                |grant| {
+                    let granted = grant.capabilities_for(path);
+                    FilesystemCapability::ALL.iter().fold(
+                        row![].spacing(10),
+                        |capability_row, capability| {
+                            let capability = *capability;
+                            capability_row.push(tooltip(
+                                checkbox(capability.short_label(), granted.contains(&capability))
+                                    .on_toggle(move |_| {
+                                        Message::PathCapabilityToggled(idx, capability)
+                                    }),
+                                capability.help_text(),
+                                tooltip::Position::Top,
+                            ))
+                        },
+                    )
+                },
+            );
+            let move_button = |label: &'a str, direction: MoveDirection, enabled: bool| {
+                button(text(label).size(14))
+                    .on_press_maybe(enabled.then_some(Message::MovePath(path_type, idx, direction)))
+                    .padding([6, 10])
+                    .style(|theme, status| {
+                        let palette = theme::palette(theme);
+                        button::Style {
+                            background: Some(iced::Background::Color(match status {
+                                button::Status::Hovered => palette.nav_inactive_hover,
+                                _ => palette.nav_inactive,
+                            })),
+                            text_color: if matches!(status, button::Status::Disabled) {
+                                palette.neutral
+                            } else {
+                                palette.text
+                            },
+                            border: Border {
+                                color: palette.nav_border,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            ..Default::default()
+                        }
+                    })
+            };
+            col.push(
+                column![
                     row![
                         text(path).width(Length::Fill),
+                        path_capabilities,
+                        move_button("↑", MoveDirection::Up, idx > 0),
+                        move_button("↓", MoveDirection::Down, idx + 1 < paths.len()),
+                        tooltip(
+                            button(text("↔").size(14))
+                                .on_press(Message::MovePathToOtherList {
+                                    path_type,
+                                    index: idx
+                                })
+                                .padding([6, 10])
+                                .style(|theme, status| {
+                                    let palette = theme::palette(theme);
+                                    button::Style {
+                                        background: Some(iced::Background::Color(match status {
+                                            button::Status::Hovered => palette.nav_inactive_hover,
+                                            _ => palette.nav_inactive,
+                                        })),
+                                        text_color: palette.text,
+                                        border: Border {
+                                            color: palette.nav_border,
+                                            width: 1.0,
+                                            radius: 2.0.into(),
+                                        },
+                                        ..Default::default()
+                                    }
+                                }),
+                            match path_type {
+                                PathType::Allowed => "Move to denied paths",
+                                PathType::Denied => "Move to allowed paths",
+                            },
+                            tooltip::Position::Top
+                        ),
                         button(text("×").size(16))
                             .on_press(Message::RemovePath {
                                 path_type,
@@ -415,13 +1318,24 @@ fn build_path_section<'a>(
                                 ..Default::default()
                             }),
                     ]
-                    .spacing(10)
-                    .padding(8),
-                )
-            });
+                    .spacing(10),
+                    text_input(
+                        "Note (optional), e.g. needed for npm cache",
+                        entry.note.as_deref().unwrap_or("")
+                    )
+                    .on_input(move |note| Message::PathNoteChanged(path_type, idx, note))
+                    .size(12)
+                    .padding(6),
+                ]
+                .spacing(6)
+                .padding(8),
+            )
+        });
 
+    let field_name = format!("{:?}_path", path_type);
     let add_input = row![
-        text_input("Enter path", input_value)
+        text_input("Enter path, or paste several (one per line)", input_value)
+            .id(field_input_id(&field_name))
             .on_input(move |value| Message::PathInputChanged { path_type, value })
             .padding(10)
             .width(Length::Fill),
@@ -441,7 +1355,6 @@ fn build_path_section<'a>(
     ]
     .spacing(10);
 
-    let field_name = format!("{:?}_path", path_type);
     let error_msg = if let Some(error) = validation_errors.fields.get(&field_name) {
         container(
             row![
@@ -464,12 +1377,37 @@ fn build_path_section<'a>(
         container(text(""))
     };
 
+    let filter_input = if paths.is_empty() {
+        row![]
+    } else {
+        row![
+            text_input("Filter paths", filter)
+                .on_input(move |value| Message::PathFilterChanged(path_type, value))
+                .size(12)
+                .padding(6)
+                .width(Length::Fill),
+            text(if needle.is_empty() {
+                String::new()
+            } else {
+                format!("{} of {}", shown, paths.len())
+            })
+            .size(12)
+            .style(theme::muted_text),
+        ]
+        .spacing(10)
+        .align_y(iced::Alignment::Center)
+    };
+
     column![
-        text(title).size(14),
+        filter_input,
         if paths.is_empty() {
             column![text("No paths configured")
                 .size(12)
                 .color(Color::from_rgb(0.5, 0.5, 0.5))]
+        } else if shown == 0 {
+            column![text(format!("No paths match \"{}\"", filter.trim()))
+                .size(12)
+                .color(Color::from_rgb(0.5, 0.5, 0.5))]
         } else {
             path_list
         },
@@ -479,3 +1417,112 @@ fn build_path_section<'a>(
     .spacing(10)
     .into()
 }
+
+pub fn unsaved_changes_confirmation<'a>(
+    profile_name: &'a str,
+    never_saved: bool,
+) -> Element<'a, Message> {
+    column![
+        text(if never_saved {
+            "UNSAVED PROFILE"
+        } else {
+            "UNSAVED CHANGES"
+        })
+        .size(20),
+        text(if never_saved {
+            format!(
+                "\"{}\" has never been saved. Save it to keep it, or discard it to remove it from the list.",
+                profile_name
+            )
+        } else {
+            format!(
+                "\"{}\" has unsaved changes. Save them before leaving?",
+                profile_name
+            )
+        })
+        .size(14)
+        .style(theme::muted_text),
+        row![
+            dialog_button(
+                "Cancel",
+                Message::ResolveUnsavedChanges(UnsavedChangesChoice::Cancel),
+                Color::from_rgb(0.4, 0.4, 0.45)
+            ),
+            dialog_button(
+                if never_saved { "Discard profile" } else { "Discard" },
+                Message::ResolveUnsavedChanges(UnsavedChangesChoice::Discard),
+                Color::from_rgb(0.8, 0.2, 0.2)
+            ),
+            dialog_button(
+                "💾 Save",
+                Message::ResolveUnsavedChanges(UnsavedChangesChoice::Save),
+                Color::from_rgb(0.2, 0.6, 0.2)
+            ),
+        ]
+        .spacing(10),
+    ]
+    .spacing(20)
+    .into()
+}
+
+pub fn external_change_confirmation<'a>(profile_name: &'a str) -> Element<'a, Message> {
+    column![
+        text("CHANGED ON DISK").size(20),
+        text(format!(
+            "\"{}\" was modified outside Hops while you were editing it. Reload it from disk or keep your edits?",
+            profile_name
+        ))
+        .size(14)
+        .style(theme::muted_text),
+        row![
+            dialog_button(
+                "Keep My Edits",
+                Message::ResolveExternalChange(ExternalChangeChoice::KeepMine),
+                Color::from_rgb(0.4, 0.4, 0.45)
+            ),
+            dialog_button(
+                "↻ Reload From Disk",
+                Message::ResolveExternalChange(ExternalChangeChoice::Reload),
+                Color::from_rgb(0.2, 0.4, 0.8)
+            ),
+        ]
+        .spacing(10),
+    ]
+    .spacing(20)
+    .into()
+}
+
+fn dialog_button(label: &str, message: Message, base_color: Color) -> button::Button<'_, Message> {
+    button(
+        text(label)
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(message)
+    .width(Length::Fill)
+    .padding(10)
+    .style(move |_theme, status| {
+        let hover_color = Color {
+            r: base_color.r + 0.05,
+            g: base_color.g + 0.05,
+            b: base_color.b + 0.05,
+            a: 1.0,
+        };
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: hover_color,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    })
+}
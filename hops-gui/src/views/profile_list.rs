@@ -1,19 +1,167 @@
-use crate::app::Message;
-use crate::models::policy::Policy;
-use iced::widget::{button, column, container, row, scrollable, text, tooltip, Column};
+use crate::app::{
+    BulkChange, BulkEdit, DeletedProfile, Message, NetworkFilter, ProfileGrouping, ProfileSortOrder,
+};
+use crate::models::capability::NetworkCapability;
+use crate::models::policy::{FieldChange, FieldDiff, Policy};
+use crate::models::templates::ProfileTemplate;
+use crate::theme;
+use iced::widget::{
+    button, checkbox, column, container, pick_list, row, scrollable, text, text_input, tooltip,
+    Column,
+};
 use iced::{Border, Color, Element, Length};
+use std::collections::{BTreeSet, HashMap};
 
-pub fn view<'a>(profiles: &'a [Policy]) -> Element<'a, Message> {
+const UNGROUPED_LABEL: &str = "Ungrouped";
+
+pub fn view<'a>(
+    profiles: &'a [Policy],
+    filter: &'a str,
+    network_filter: NetworkFilter,
+    sort_order: ProfileSortOrder,
+    group_by: Option<&'a str>,
+    highlighted: Option<usize>,
+    last_deleted: Option<&'a DeletedProfile>,
+) -> Element<'a, Message> {
     let title = text("PROFILES").size(32);
 
+    let filter_input = row![
+        text("Search:").width(Length::Fixed(60.0)),
+        text_input("Search by name or description", filter)
+            .on_input(Message::ProfileFilterChanged)
+            .padding(10)
+            .width(Length::Fill),
+        pick_list(
+            NetworkFilter::ALL,
+            Some(network_filter),
+            Message::ProfileNetworkFilterChanged
+        )
+        .padding(10)
+        .width(Length::Fixed(150.0)),
+        pick_list(
+            ProfileSortOrder::ALL,
+            Some(sort_order),
+            Message::ProfileSortChanged
+        )
+        .padding(10)
+        .width(Length::Fixed(200.0)),
+        pick_list(
+            grouping_options(profiles, group_by),
+            Some(group_by.map_or(ProfileGrouping::None, |key| {
+                ProfileGrouping::Metadata(key.to_string())
+            })),
+            Message::ProfileGroupingChanged
+        )
+        .padding(10)
+        .width(Length::Fixed(180.0)),
+    ]
+    .spacing(10);
+
+    let summary = if profiles.is_empty() {
+        container(row![])
+    } else {
+        let total = container(
+            text(format!("📊 Total: {}", profiles.len()))
+                .size(14)
+                .style(|theme| text::Style {
+                    color: Some(theme::palette(theme).text),
+                }),
+        )
+        .padding([6, 12])
+        .style(|theme| container::Style {
+            background: Some(iced::Background::Color(
+                theme::palette(theme).surface_raised,
+            )),
+            border: Border {
+                color: theme::palette(theme).border,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            ..Default::default()
+        });
+        let by_network = NetworkCapability::ALL.iter().rev().fold(
+            row![total].spacing(15),
+            |summary_row, &network| {
+                let count = profiles
+                    .iter()
+                    .filter(|p| p.capabilities.network == network)
+                    .count();
+                if count == 0 {
+                    return summary_row;
+                }
+                let (background, border) = match network {
+                    NetworkCapability::Full => (
+                        Color::from_rgb(0.5, 0.15, 0.15),
+                        Color::from_rgb(0.7, 0.25, 0.25),
+                    ),
+                    NetworkCapability::Outbound => (
+                        Color::from_rgb(0.5, 0.3, 0.1),
+                        Color::from_rgb(0.7, 0.4, 0.15),
+                    ),
+                    NetworkCapability::Loopback => (
+                        Color::from_rgb(0.15, 0.3, 0.5),
+                        Color::from_rgb(0.25, 0.45, 0.7),
+                    ),
+                    NetworkCapability::Disabled => (
+                        Color::from_rgb(0.15, 0.4, 0.15),
+                        Color::from_rgb(0.3, 0.6, 0.3),
+                    ),
+                };
+                summary_row.push(
+                    container(
+                        text(format!("{} {}", count, network))
+                            .size(14)
+                            .color(Color::from_rgb(0.95, 0.95, 0.95)),
+                    )
+                    .padding([6, 12])
+                    .style(move |_theme| container::Style {
+                        background: Some(iced::Background::Color(background)),
+                        border: Border {
+                            color: border,
+                            width: 1.0,
+                            radius: 6.0.into(),
+                        },
+                        ..Default::default()
+                    }),
+                )
+            },
+        );
+        container(by_network)
+    };
+
+    let filtered_profiles =
+        visible_profiles(profiles, filter, network_filter, sort_order, group_by);
+
+    let mut group_headers: HashMap<usize, String> = HashMap::new();
+    if let Some(key) = group_by {
+        let mut start = 0;
+        while let Some(&(first, profile)) = filtered_profiles.get(start) {
+            let group = profile_group(profile, key);
+            let len = filtered_profiles[start..]
+                .iter()
+                .take_while(|(_, p)| profile_group(p, key) == group)
+                .count();
+            group_headers.insert(
+                first,
+                format!("{} ({})", group.unwrap_or(UNGROUPED_LABEL), len),
+            );
+            start += len;
+        }
+    }
+
     let profile_list: Column<Message> =
-        profiles
+        filtered_profiles
             .iter()
-            .enumerate()
-            .fold(Column::new().spacing(15), |col, (idx, profile)| {
+            .fold(Column::new().spacing(15), |col, &(idx, profile)| {
+                let col = match group_headers.get(&idx) {
+                    Some(header) => {
+                        col.push(text(header.clone()).size(16).style(theme::muted_text))
+                    }
+                    None => col,
+                };
                 let profile_header = text(&profile.name).size(18);
 
-                let network_badge = text(format!("Network: {:?}", profile.capabilities.network))
+                let network_badge = text(format!("Network: {}", profile.capabilities.network))
                     .size(12)
                     .color(Color::from_rgb(0.6, 0.6, 0.6));
 
@@ -124,9 +272,10 @@ pub fn view<'a>(profiles: &'a [Policy]) -> Element<'a, Message> {
                 let duplicate_btn = button(text("⎘ Duplicate").size(14))
                     .on_press(Message::DuplicateProfile(idx))
                     .padding(10)
-                    .style(|_theme, status| {
-                        let base_color = Color::from_rgb(0.4, 0.4, 0.45);
-                        let hover_color = Color::from_rgb(0.45, 0.45, 0.5);
+                    .style(|theme, status| {
+                        let palette = theme::palette(theme);
+                        let base_color = palette.neutral;
+                        let hover_color = palette.neutral_hover;
                         button::Style {
                             background: Some(iced::Background::Color(
                                 if matches!(status, button::Status::Hovered) {
@@ -137,13 +286,16 @@ pub fn view<'a>(profiles: &'a [Policy]) -> Element<'a, Message> {
                             )),
                             text_color: Color::WHITE,
                             border: Border {
-                                color: Color::from_rgb(0.5, 0.5, 0.55),
+                                color: palette.neutral_border,
                                 width: 1.0,
                                 radius: 4.0.into(),
                             },
                             shadow: if matches!(status, button::Status::Hovered) {
                                 iced::Shadow {
-                                    color: Color::from_rgba(0.4, 0.4, 0.45, 0.4),
+                                    color: Color {
+                                        a: 0.4,
+                                        ..palette.neutral
+                                    },
                                     offset: iced::Vector::new(0.0, 2.0),
                                     blur_radius: 8.0,
                                 }
@@ -155,7 +307,7 @@ pub fn view<'a>(profiles: &'a [Policy]) -> Element<'a, Message> {
                     });
 
                 let delete_btn = button(text("🗑 Delete").size(14))
-                    .on_press(Message::DeleteProfile(idx))
+                    .on_press(Message::RequestDeleteProfile(idx))
                     .padding(10)
                     .style(|_theme, status| {
                         let base_color = Color::from_rgb(0.8, 0.2, 0.2);
@@ -188,6 +340,7 @@ pub fn view<'a>(profiles: &'a [Policy]) -> Element<'a, Message> {
                     });
 
                 let button_row = row![edit_btn, duplicate_btn, delete_btn].spacing(10);
+                let is_highlighted = highlighted == Some(idx);
 
                 let profile_card = container(
                     column![row![info_column, button_row].spacing(15)]
@@ -195,15 +348,23 @@ pub fn view<'a>(profiles: &'a [Policy]) -> Element<'a, Message> {
                         .padding(20),
                 )
                 .width(Length::Fill)
-                .style(|_theme| container::Style {
-                    background: Some(iced::Background::Color(Color::from_rgb(0.16, 0.16, 0.18))),
-                    border: Border {
-                        color: Color::from_rgb(0.35, 0.35, 0.4),
-                        width: 1.0,
-                        radius: 8.0.into(),
+                .style(move |theme| container::Style {
+                    background: Some(iced::Background::Color(theme::palette(theme).surface)),
+                    border: if is_highlighted {
+                        Border {
+                            color: Color::from_rgb(0.3, 0.6, 0.9),
+                            width: 2.0,
+                            radius: 8.0.into(),
+                        }
+                    } else {
+                        Border {
+                            color: theme::palette(theme).border,
+                            width: 1.0,
+                            radius: 8.0.into(),
+                        }
                     },
                     shadow: iced::Shadow {
-                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+                        color: theme::palette(theme).shadow,
                         offset: iced::Vector::new(0.0, 4.0),
                         blur_radius: 12.0,
                     },
@@ -268,15 +429,297 @@ pub fn view<'a>(profiles: &'a [Policy]) -> Element<'a, Message> {
                 .color(Color::from_rgb(0.6, 0.6, 0.6))
         ]
         .spacing(10)
+    } else if filtered_profiles.is_empty() {
+        column![text("No matching profiles.")
+            .size(16)
+            .color(Color::from_rgb(0.6, 0.6, 0.6))]
     } else {
         column![]
     };
 
+    let import_btn = button(
+        text("⇩ IMPORT")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::ImportProfile)
+    .width(Length::Fixed(160.0))
+    .padding(16)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let paste_btn = button(
+        text("📋 PASTE")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::PasteProfile)
+    .width(Length::Fixed(160.0))
+    .padding(16)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let reload_btn = button(
+        text("↻ RELOAD")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::ReloadProfiles)
+    .width(Length::Fixed(160.0))
+    .padding(16)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let export_all_btn = button(
+        text("⇪ EXPORT ALL")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::ExportAllProfiles)
+    .width(Length::Fixed(160.0))
+    .padding(16)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let import_all_btn = button(
+        text("⇩ IMPORT ALL")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::ImportAllProfiles)
+    .width(Length::Fixed(160.0))
+    .padding(16)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let template_btn = button(
+        text("✦ FROM TEMPLATE")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::OpenTemplateGallery)
+    .width(Length::Fixed(200.0))
+    .padding(16)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let list_actions = row![
+        new_profile_btn,
+        template_btn,
+        import_btn,
+        paste_btn,
+        reload_btn
+    ]
+    .spacing(10);
+
+    let bulk_edit_btn = button(
+        text("⇶ APPLY TO ALL")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press_maybe((!profiles.is_empty()).then_some(Message::OpenBulkEdit))
+    .width(Length::Fixed(180.0))
+    .padding(16)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let archive_actions = row![export_all_btn, import_all_btn, bulk_edit_btn].spacing(10);
+
+    let undo_banner = if let Some(deleted) = last_deleted {
+        container(
+            row![
+                text(format!("Profile \"{}\" deleted", deleted.name))
+                    .size(14)
+                    .style(|theme| text::Style {
+                        color: Some(theme::palette(theme).text),
+                    })
+                    .width(Length::Fill),
+                button(text("↶ Undo").size(14))
+                    .on_press(Message::UndoDelete)
+                    .padding([6, 14])
+                    .style(|_theme, status| {
+                        let base_color = Color::from_rgb(0.2, 0.5, 0.8);
+                        let hover_color = Color::from_rgb(0.25, 0.55, 0.85);
+                        button::Style {
+                            background: Some(iced::Background::Color(
+                                if matches!(status, button::Status::Hovered) {
+                                    hover_color
+                                } else {
+                                    base_color
+                                },
+                            )),
+                            text_color: Color::WHITE,
+                            border: Border {
+                                color: Color::from_rgb(0.3, 0.6, 0.9),
+                                width: 1.0,
+                                radius: 4.0.into(),
+                            },
+                            ..Default::default()
+                        }
+                    }),
+            ]
+            .spacing(15)
+            .align_y(iced::alignment::Vertical::Center),
+        )
+        .width(Length::Fill)
+        .padding([10, 16])
+        .style(|theme| container::Style {
+            background: Some(iced::Background::Color(
+                theme::palette(theme).surface_raised,
+            )),
+            border: Border {
+                color: theme::palette(theme).border,
+                width: 1.0,
+                radius: 6.0.into(),
+            },
+            ..Default::default()
+        })
+    } else {
+        container(row![])
+    };
+
     let content = column![
         title,
+        undo_banner,
+        summary,
+        filter_input,
         empty_state,
         scrollable(profile_list),
-        new_profile_btn,
+        list_actions,
+        archive_actions,
     ]
     .spacing(20)
     .padding(30);
@@ -286,3 +729,354 @@ pub fn view<'a>(profiles: &'a [Policy]) -> Element<'a, Message> {
         .height(Length::Fill)
         .into()
 }
+
+fn profile_group<'a>(profile: &'a Policy, key: &str) -> Option<&'a str> {
+    profile
+        .metadata
+        .get(key)
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+fn grouping_options(profiles: &[Policy], group_by: Option<&str>) -> Vec<ProfileGrouping> {
+    let keys: BTreeSet<&str> = profiles
+        .iter()
+        .flat_map(|p| p.metadata.keys().map(String::as_str))
+        .chain(group_by)
+        .collect();
+    std::iter::once(ProfileGrouping::None)
+        .chain(
+            keys.into_iter()
+                .map(|key| ProfileGrouping::Metadata(key.to_string())),
+        )
+        .collect()
+}
+
+pub fn visible_profiles<'a>(
+    profiles: &'a [Policy],
+    filter: &str,
+    network_filter: NetworkFilter,
+    sort_order: ProfileSortOrder,
+    group_by: Option<&str>,
+) -> Vec<(usize, &'a Policy)> {
+    let query = filter.to_lowercase();
+    let mut visible: Vec<(usize, &Policy)> = profiles
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| network_filter.matches(p.capabilities.network))
+        .filter(|(_, p)| {
+            query.is_empty()
+                || p.name.to_lowercase().contains(&query)
+                || p.description
+                    .as_ref()
+                    .is_some_and(|d| d.to_lowercase().contains(&query))
+        })
+        .collect();
+
+    match sort_order {
+        ProfileSortOrder::NameAscending => {
+            visible.sort_by_key(|(_, p)| p.name.to_lowercase());
+        }
+        ProfileSortOrder::NameDescending => {
+            visible.sort_by_key(|(_, p)| std::cmp::Reverse(p.name.to_lowercase()));
+        }
+        ProfileSortOrder::RecentlyModified => {
+            visible.sort_by_key(|(_, p)| std::cmp::Reverse(p.modified));
+        }
+    }
+    if let Some(key) = group_by {
+        visible.sort_by_key(|(_, p)| {
+            profile_group(p, key).map_or((true, String::new()), |g| (false, g.to_lowercase()))
+        });
+    }
+
+    visible
+}
+
+pub fn template_gallery<'a>() -> Element<'a, Message> {
+    let cards = ProfileTemplate::ALL
+        .iter()
+        .fold(Column::new().spacing(10), |col, template| {
+            col.push(
+                button(
+                    row![
+                        text(template.icon).size(24),
+                        column![
+                            text(template.name).size(16),
+                            text(template.description).size(12).style(theme::muted_text),
+                            text(template.summary())
+                                .size(11)
+                                .color(Color::from_rgb(0.4, 0.7, 1.0)),
+                        ]
+                        .spacing(4)
+                        .width(Length::Fill),
+                    ]
+                    .spacing(14)
+                    .align_y(iced::alignment::Vertical::Center),
+                )
+                .on_press(Message::CreateFromTemplate(template))
+                .width(Length::Fill)
+                .padding(12)
+                .style(|theme, status| {
+                    let palette = theme::palette(theme);
+                    button::Style {
+                        background: Some(iced::Background::Color(match status {
+                            button::Status::Hovered => palette.nav_inactive_hover,
+                            _ => palette.nav_inactive,
+                        })),
+                        text_color: palette.text,
+                        border: Border {
+                            color: palette.nav_border,
+                            width: 1.0,
+                            radius: 6.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                }),
+            )
+        });
+
+    let cancel_btn = button(
+        text("Cancel")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::CloseTemplateGallery)
+    .width(Length::Fill)
+    .padding(10)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    column![
+        text("NEW FROM TEMPLATE").size(20),
+        text("Start from a preset and adjust it in the editor")
+            .size(14)
+            .style(theme::muted_text),
+        cards,
+        cancel_btn,
+    ]
+    .spacing(20)
+    .into()
+}
+
+pub fn delete_confirmation<'a>(profile_name: &'a str, index: usize) -> Element<'a, Message> {
+    let cancel_btn = button(
+        text("Cancel")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::CancelDeleteProfile)
+    .width(Length::Fill)
+    .padding(10)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let delete_btn = button(
+        text("🗑 Delete")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::DeleteProfile(index))
+    .width(Length::Fill)
+    .padding(10)
+    .style(|_theme, status| {
+        let base_color = Color::from_rgb(0.8, 0.2, 0.2);
+        let hover_color = Color::from_rgb(0.85, 0.25, 0.25);
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: Color::from_rgb(0.9, 0.3, 0.3),
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    column![
+        text("DELETE PROFILE").size(20),
+        text(format!(
+            "Are you sure you want to delete \"{}\"?",
+            profile_name
+        ))
+        .size(14)
+        .style(theme::muted_text),
+        row![cancel_btn, delete_btn].spacing(10),
+    ]
+    .spacing(20)
+    .into()
+}
+
+pub fn bulk_edit<'a>(
+    edit: BulkEdit,
+    preview: Vec<(String, Vec<FieldDiff>)>,
+    conflict: Option<&str>,
+) -> Element<'a, Message> {
+    let scope = checkbox(
+        "Only profiles matching the current search and network filter",
+        edit.filtered_only,
+    )
+    .on_toggle(Message::BulkFilteredOnlyToggled);
+
+    let has_changes = !preview.is_empty();
+    let summary = if preview.is_empty() {
+        "No profiles would change".to_string()
+    } else {
+        format!(
+            "{} profile{} will be updated and saved:",
+            preview.len(),
+            if preview.len() == 1 { "" } else { "s" }
+        )
+    };
+
+    let changes = preview
+        .into_iter()
+        .fold(Column::new().spacing(10), |col, (name, diffs)| {
+            let lines = diffs
+                .into_iter()
+                .fold(Column::new().spacing(2), |lines, diff| {
+                    let value = match diff.change {
+                        FieldChange::Added(value) => format!("+ {}", value),
+                        FieldChange::Removed(value) => format!("− {}", value),
+                        FieldChange::Changed { from, to } => format!("{} → {}", from, to),
+                    };
+                    lines.push(
+                        text(format!("{}: {}", diff.field, value))
+                            .size(12)
+                            .style(theme::muted_text),
+                    )
+                });
+            col.push(column![text(name).size(14), lines].spacing(4))
+        });
+
+    let conflict_warning: Element<'a, Message> = match conflict {
+        Some(name) => text(format!(
+            "\"{}\" has unsaved changes. Save or discard them before applying",
+            name
+        ))
+        .size(12)
+        .color(Color::from_rgb(1.0, 0.7, 0.0))
+        .into(),
+        None => Column::new().into(),
+    };
+
+    let cancel_btn = button(
+        text("Cancel")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::CancelBulkEdit)
+    .width(Length::Fill)
+    .padding(10)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        let base_color = palette.neutral;
+        let hover_color = palette.neutral_hover;
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    hover_color
+                } else {
+                    base_color
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let apply_btn = button(
+        text("Apply and save")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press_maybe((has_changes && conflict.is_none()).then_some(Message::ApplyBulkEdit))
+    .width(Length::Fill)
+    .padding(10)
+    .style(|_theme, status| {
+        let base_color = Color::from_rgb(0.2, 0.5, 0.8);
+        let hover_color = Color::from_rgb(0.25, 0.55, 0.85);
+        button::Style {
+            background: Some(iced::Background::Color(match status {
+                button::Status::Hovered => hover_color,
+                button::Status::Disabled => Color::from_rgb(0.35, 0.35, 0.35),
+                _ => base_color,
+            })),
+            text_color: Color::WHITE,
+            border: Border {
+                color: Color::from_rgb(0.3, 0.6, 0.9),
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    column![
+        text("APPLY TO ALL PROFILES").size(20),
+        pick_list(
+            BulkChange::ALL,
+            Some(edit.change),
+            Message::BulkChangeSelected
+        )
+        .padding(10)
+        .width(Length::Fill),
+        scope,
+        text(summary).size(14).style(theme::muted_text),
+        scrollable(changes).height(Length::Fixed(240.0)),
+        conflict_warning,
+        row![cancel_btn, apply_btn].spacing(10),
+    ]
+    .spacing(16)
+    .into()
+}
@@ -1,148 +1,519 @@
-use crate::app::{Message, RunRecord};
-use iced::widget::{column, container, row, scrollable, text, text_input, Column};
+use crate::app::{
+    DaemonStatus, HistoryFilter, HistorySortOrder, Message, PendingStop, RunRecord, SandboxDetails,
+};
+use crate::grpc_client::hops::{ResourceUsage, SandboxState};
+use crate::theme;
+use iced::widget::{
+    button, checkbox, column, container, mouse_area, pick_list, progress_bar, rich_text, row,
+    scrollable, span, text, text_input, tooltip, Column,
+};
 use iced::{Border, Color, Element, Length};
+use std::collections::HashMap;
 
-pub fn view<'a>(records: &'a [RunRecord], filter: &'a str) -> Element<'a, Message> {
-    let title = text("RUN HISTORY").size(32);
+const SECONDS_PER_DAY: i64 = 86_400;
+const PAGE_SIZE: usize = 25;
+const SPARKLINE_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[allow(clippy::too_many_arguments)]
+pub fn view<'a>(
+    records: &'a [RunRecord],
+    filter: &'a HistoryFilter,
+    sort_order: HistorySortOrder,
+    page: usize,
+    auto_refresh: bool,
+    loading: bool,
+    stats: &'a HashMap<String, Vec<ResourceUsage>>,
+    daemon_status: DaemonStatus,
+    connection_error: Option<&'a str>,
+) -> Element<'a, Message> {
+    let title = row![
+        text("RUN HISTORY").size(32),
+        text(if loading { "⏳ Refreshing…" } else { "" })
+            .size(12)
+            .style(theme::muted_text)
+            .width(Length::Fill),
+        checkbox("Auto-refresh", auto_refresh).on_toggle(Message::HistoryAutoRefreshToggled),
+        button(text("⏹ Stop all").size(14))
+            .on_press_maybe(
+                records
+                    .iter()
+                    .any(RunRecord::is_running)
+                    .then_some(Message::RequestStopAll),
+            )
+            .padding([8, 14])
+            .style(|_theme, status| button::Style {
+                background: Some(iced::Background::Color(match status {
+                    button::Status::Hovered => Color::from_rgb(0.85, 0.25, 0.25),
+                    button::Status::Disabled => Color::from_rgb(0.35, 0.25, 0.25),
+                    _ => Color::from_rgb(0.8, 0.2, 0.2),
+                })),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: Color::from_rgb(0.9, 0.3, 0.3),
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }),
+        button(text("🗑 Clear").size(14))
+            .on_press_maybe(
+                records
+                    .iter()
+                    .any(|r| !r.is_running())
+                    .then_some(Message::RequestClearHistory),
+            )
+            .padding([8, 14])
+            .style(|theme, status| {
+                let palette = theme::palette(theme);
+                button::Style {
+                    background: Some(iced::Background::Color(match status {
+                        button::Status::Hovered => palette.neutral_hover,
+                        button::Status::Disabled => palette.nav_inactive,
+                        _ => palette.neutral,
+                    })),
+                    text_color: if matches!(status, button::Status::Disabled) {
+                        palette.neutral
+                    } else {
+                        Color::WHITE
+                    },
+                    border: Border {
+                        color: palette.neutral_border,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    ..Default::default()
+                }
+            }),
+        button(text("⇪ Export CSV").size(14))
+            .on_press_maybe((!records.is_empty()).then_some(Message::ExportHistoryCsv))
+            .padding([8, 14])
+            .style(|theme, status| {
+                let palette = theme::palette(theme);
+                button::Style {
+                    background: Some(iced::Background::Color(match status {
+                        button::Status::Hovered => palette.neutral_hover,
+                        button::Status::Disabled => palette.nav_inactive,
+                        _ => palette.neutral,
+                    })),
+                    text_color: if matches!(status, button::Status::Disabled) {
+                        palette.neutral
+                    } else {
+                        Color::WHITE
+                    },
+                    border: Border {
+                        color: palette.neutral_border,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    ..Default::default()
+                }
+            }),
+        button(text("↻ Refresh").size(14))
+            .on_press(Message::RefreshHistory)
+            .padding([8, 14])
+            .style(|_theme, status| button::Style {
+                background: Some(iced::Background::Color(
+                    if matches!(status, button::Status::Hovered) {
+                        Color::from_rgb(0.25, 0.55, 0.85)
+                    } else {
+                        Color::from_rgb(0.2, 0.5, 0.8)
+                    },
+                )),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: Color::from_rgb(0.3, 0.6, 0.9),
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }),
+    ]
+    .spacing(15)
+    .align_y(iced::alignment::Vertical::Center);
 
     let filter_input = row![
         text("Filter:").width(Length::Fixed(60.0)),
-        text_input("Search by ID, profile, or status", filter)
+        text_input("Search by ID, profile, status, or denial", &filter.input)
             .on_input(Message::HistoryFilterChanged)
             .padding(10)
             .width(Length::Fill),
+        pick_list(
+            HistorySortOrder::ALL,
+            Some(sort_order),
+            Message::HistorySortChanged
+        )
+        .padding(10)
+        .width(Length::Fixed(180.0)),
     ]
     .spacing(10);
 
-    let filtered_records: Vec<&RunRecord> = if filter.is_empty() {
-        records.iter().collect()
-    } else {
-        records
-            .iter()
-            .filter(|r| {
-                r.id.contains(filter)
-                    || r.profile_name.contains(filter)
-                    || r.exit_code.to_string().contains(filter)
-            })
-            .collect()
-    };
+    let from_bound = parse_date_bound(&filter.from);
+    let to_bound = parse_date_bound(&filter.to);
+    let invalid_bound = from_bound.is_err() || to_bound.is_err();
+    let from_bound = from_bound.ok().flatten().unwrap_or(i64::MIN);
+    let to_bound = to_bound
+        .ok()
+        .flatten()
+        .map_or(i64::MAX, |day| day + SECONDS_PER_DAY);
 
-    let history_list: Column<Message> =
-        filtered_records
-            .iter()
-            .fold(Column::new().spacing(15), |col, record| {
-                let status_badge = if record.exit_code == 0 {
-                    container(text("✓ SUCCESS").size(12).color(Color::WHITE))
-                        .padding([4, 12])
-                        .style(|_theme| container::Style {
-                            background: Some(iced::Background::Color(Color::from_rgb(
-                                0.2, 0.6, 0.2,
-                            ))),
-                            border: Border {
-                                color: Color::from_rgb(0.3, 0.7, 0.3),
-                                width: 1.0,
-                                radius: 12.0.into(),
-                            },
-                            ..Default::default()
-                        })
-                } else {
-                    container(
-                        text(format!("✗ FAILED ({})", record.exit_code))
-                            .size(12)
-                            .color(Color::WHITE),
-                    )
+    let date_inputs = row![
+        text("From:").width(Length::Fixed(60.0)),
+        text_input("YYYY-MM-DD", &filter.from)
+            .on_input(Message::HistoryDateFromChanged)
+            .padding(10)
+            .width(Length::Fixed(140.0)),
+        text("To:"),
+        text_input("YYYY-MM-DD", &filter.to)
+            .on_input(Message::HistoryDateToChanged)
+            .padding(10)
+            .width(Length::Fixed(140.0)),
+        if invalid_bound {
+            text("Dates must use YYYY-MM-DD")
+                .size(12)
+                .color(Color::from_rgb(0.9, 0.4, 0.4))
+        } else {
+            text("Leave a date empty for no limit")
+                .size(12)
+                .style(theme::muted_text)
+        },
+    ]
+    .spacing(10)
+    .align_y(iced::alignment::Vertical::Center);
+
+    let query = normalize_query(&filter.query);
+    let mut filtered_records: Vec<&RunRecord> = records
+        .iter()
+        .filter(|r| query.is_empty() || record_matches(r, &query))
+        .filter(|r| (from_bound..to_bound).contains(&r.started_at))
+        .collect();
+
+    match sort_order {
+        HistorySortOrder::Newest => {
+            filtered_records.sort_by_key(|r| std::cmp::Reverse(r.started_at));
+        }
+        HistorySortOrder::Oldest => {
+            filtered_records.sort_by_key(|r| r.started_at);
+        }
+        HistorySortOrder::Failures => {
+            filtered_records.sort_by_key(|r| (r.exit_code == 0, std::cmp::Reverse(r.started_at)));
+        }
+    }
+
+    let page_count = filtered_records.len().div_ceil(PAGE_SIZE).max(1);
+    let page = page.min(page_count - 1);
+
+    let history_list: Column<Message> = filtered_records
+        .iter()
+        .skip(page * PAGE_SIZE)
+        .take(PAGE_SIZE)
+        .fold(Column::new().spacing(15), |col, record| {
+            let status_badge = if record.is_running() {
+                container(text("● RUNNING").size(12).color(Color::WHITE))
                     .padding([4, 12])
                     .style(|_theme| container::Style {
-                        background: Some(iced::Background::Color(Color::from_rgb(0.8, 0.2, 0.2))),
+                        background: Some(iced::Background::Color(Color::from_rgb(0.2, 0.45, 0.75))),
                         border: Border {
-                            color: Color::from_rgb(0.9, 0.3, 0.3),
+                            color: Color::from_rgb(0.3, 0.55, 0.85),
                             width: 1.0,
                             radius: 12.0.into(),
                         },
                         ..Default::default()
                     })
-                };
+            } else if record.exit_code == 0 {
+                container(text("✓ SUCCESS").size(12).color(Color::WHITE))
+                    .padding([4, 12])
+                    .style(|_theme| container::Style {
+                        background: Some(iced::Background::Color(Color::from_rgb(0.2, 0.6, 0.2))),
+                        border: Border {
+                            color: Color::from_rgb(0.3, 0.7, 0.3),
+                            width: 1.0,
+                            radius: 12.0.into(),
+                        },
+                        ..Default::default()
+                    })
+            } else {
+                container(
+                    text(format!("✗ FAILED ({})", record.exit_code))
+                        .size(12)
+                        .color(Color::WHITE),
+                )
+                .padding([4, 12])
+                .style(|_theme| container::Style {
+                    background: Some(iced::Background::Color(Color::from_rgb(0.8, 0.2, 0.2))),
+                    border: Border {
+                        color: Color::from_rgb(0.9, 0.3, 0.3),
+                        width: 1.0,
+                        radius: 12.0.into(),
+                    },
+                    ..Default::default()
+                })
+            };
 
-                let header = row![
-                    text(&record.id).size(16).width(Length::Fixed(200.0)),
-                    text(format!("📦 {}", record.profile_name))
-                        .size(14)
-                        .color(Color::from_rgb(0.7, 0.7, 0.7))
-                        .width(Length::Fill),
-                    status_badge,
+            let header = row![
+                row![
+                    highlighted(None, &record.id, &query).size(16),
+                    tooltip(
+                        button(text("⎘").size(12))
+                            .on_press(Message::CopySandboxId(record.id.clone()))
+                            .padding([2, 6])
+                            .style(|theme, status| {
+                                let palette = theme::palette(theme);
+                                button::Style {
+                                    background: Some(iced::Background::Color(match status {
+                                        button::Status::Hovered => palette.nav_inactive_hover,
+                                        _ => palette.nav_inactive,
+                                    })),
+                                    text_color: palette.text,
+                                    border: Border {
+                                        color: palette.nav_border,
+                                        width: 1.0,
+                                        radius: 4.0.into(),
+                                    },
+                                    ..Default::default()
+                                }
+                            }),
+                        "Copy sandbox id",
+                        tooltip::Position::Top
+                    ),
                 ]
-                .spacing(15)
-                .align_y(iced::alignment::Vertical::Center);
+                .spacing(6)
+                .align_y(iced::Alignment::Center)
+                .width(Length::Fixed(220.0)),
+                highlighted(Some("📦 "), &record.profile_name, &query)
+                    .size(14)
+                    .style(theme::muted_text)
+                    .width(Length::Fill),
+                status_badge,
+                button(text("↻ Re-run").size(12))
+                    .on_press_maybe(
+                        (!record.is_running() && !record.command.is_empty())
+                            .then(|| Message::RerunSandbox(record.id.clone())),
+                    )
+                    .padding([4, 12])
+                    .style(|theme, status| {
+                        let palette = theme::palette(theme);
+                        button::Style {
+                            background: Some(iced::Background::Color(match status {
+                                button::Status::Disabled => palette.nav_inactive,
+                                button::Status::Hovered => Color::from_rgb(0.25, 0.55, 0.85),
+                                _ => Color::from_rgb(0.2, 0.5, 0.8),
+                            })),
+                            text_color: if matches!(status, button::Status::Disabled) {
+                                palette.neutral
+                            } else {
+                                Color::WHITE
+                            },
+                            border: Border {
+                                color: palette.nav_border,
+                                width: 1.0,
+                                radius: 12.0.into(),
+                            },
+                            ..Default::default()
+                        }
+                    }),
+                button(text("■ Stop").size(12))
+                    .on_press_maybe(
+                        record
+                            .is_running()
+                            .then(|| Message::RequestStopSandbox(record.id.clone())),
+                    )
+                    .padding([4, 12])
+                    .style(|theme, status| {
+                        let palette = theme::palette(theme);
+                        button::Style {
+                            background: Some(iced::Background::Color(match status {
+                                button::Status::Disabled => palette.nav_inactive,
+                                button::Status::Hovered => Color::from_rgb(0.9, 0.3, 0.3),
+                                _ => Color::from_rgb(0.8, 0.2, 0.2),
+                            })),
+                            text_color: if matches!(status, button::Status::Disabled) {
+                                palette.neutral
+                            } else {
+                                Color::WHITE
+                            },
+                            border: Border {
+                                color: palette.nav_border,
+                                width: 1.0,
+                                radius: 12.0.into(),
+                            },
+                            ..Default::default()
+                        }
+                    }),
+            ]
+            .spacing(15)
+            .align_y(iced::alignment::Vertical::Center);
 
-                let details = row![
-                    text(format!("🕒 {}", record.start_time))
-                        .size(12)
-                        .color(Color::from_rgb(0.65, 0.65, 0.7)),
-                    text(format!("⏱ {}", record.duration))
+            let details = row![
+                text(format!("🕒 {}", record.start_time))
+                    .size(12)
+                    .color(Color::from_rgb(0.65, 0.65, 0.7)),
+                text(format!("⏱ {}", record.duration))
+                    .size(12)
+                    .color(Color::from_rgb(0.65, 0.65, 0.7)),
+                text(if record.pid > 0 {
+                    format!("⚙ PID {}", record.pid)
+                } else {
+                    "⚙ PID —".to_string()
+                })
+                .size(12)
+                .color(Color::from_rgb(0.65, 0.65, 0.7)),
+            ]
+            .spacing(25);
+
+            let denied_section = if record.denied_capabilities.is_empty() {
+                column![text("No denied capabilities")
+                    .size(12)
+                    .color(Color::from_rgb(0.5, 0.5, 0.5))]
+            } else {
+                let denied_list: Column<Message> = record.denied_capabilities.iter().fold(
+                    Column::new().spacing(4),
+                    |col, denied| {
+                        col.push(
+                            text(format!("  ⚠ {}", denied))
+                                .size(12)
+                                .color(Color::from_rgb(0.9, 0.5, 0.2)),
+                        )
+                    },
+                );
+                column![
+                    text("Denied Capabilities:")
                         .size(12)
-                        .color(Color::from_rgb(0.65, 0.65, 0.7)),
+                        .color(Color::from_rgb(0.9, 0.5, 0.2)),
+                    denied_list,
                 ]
-                .spacing(25);
+                .spacing(6)
+            };
 
-                let denied_section = if record.denied_capabilities.is_empty() {
-                    column![text("No denied capabilities")
+            let samples = stats.get(&record.id).filter(|_| record.is_running());
+            let usage_section = match samples.and_then(|s| s.last().map(|latest| (s, latest))) {
+                Some((samples, latest)) => column![
+                    row![
+                        text("CPU")
+                            .size(12)
+                            .style(theme::muted_text)
+                            .width(Length::Fixed(60.0)),
+                        progress_bar(0.0..=100.0, latest.cpu_percent.min(100.0) as f32)
+                            .height(8)
+                            .width(Length::Fixed(160.0)),
+                        text(format!("{:.1}%", latest.cpu_percent))
+                            .size(12)
+                            .width(Length::Fixed(70.0)),
+                        text(sparkline(samples.iter().map(|s| s.cpu_percent)))
+                            .size(12)
+                            .color(Color::from_rgb(0.4, 0.7, 1.0)),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::alignment::Vertical::Center),
+                    row![
+                        text("Memory")
+                            .size(12)
+                            .style(theme::muted_text)
+                            .width(Length::Fixed(60.0)),
+                        text(format!(
+                            "{:.1} MB · {} processes",
+                            latest.memory_bytes as f64 / (1024.0 * 1024.0),
+                            latest.process_count
+                        ))
                         .size(12)
-                        .color(Color::from_rgb(0.5, 0.5, 0.5))]
-                } else {
-                    let denied_list: Column<Message> = record.denied_capabilities.iter().fold(
-                        Column::new().spacing(4),
-                        |col, denied| {
-                            col.push(
-                                text(format!("  ⚠ {}", denied))
-                                    .size(12)
-                                    .color(Color::from_rgb(0.9, 0.5, 0.2)),
-                            )
-                        },
-                    );
-                    column![
-                        text("Denied Capabilities:")
+                        .width(Length::Fixed(240.0)),
+                        text(sparkline(samples.iter().map(|s| s.memory_bytes as f64)))
                             .size(12)
-                            .color(Color::from_rgb(0.9, 0.5, 0.2)),
-                        denied_list,
+                            .color(Color::from_rgb(0.5, 0.85, 0.5)),
                     ]
-                    .spacing(6)
-                };
-
-                let has_denials = !record.denied_capabilities.is_empty();
-                let failed = record.exit_code != 0;
-                let border_color = if has_denials {
-                    Color::from_rgb(0.9, 0.5, 0.2)
-                } else if failed {
-                    Color::from_rgb(0.6, 0.25, 0.25)
-                } else {
-                    Color::from_rgb(0.35, 0.35, 0.4)
-                };
+                    .spacing(10)
+                    .align_y(iced::alignment::Vertical::Center),
+                ]
+                .spacing(6),
+                _ => column![],
+            };
 
-                let card = container(
-                    column![header, details, denied_section]
-                        .spacing(12)
-                        .padding(20),
-                )
-                .width(Length::Fill)
-                .style(move |_theme| container::Style {
-                    background: Some(iced::Background::Color(Color::from_rgb(0.16, 0.16, 0.18))),
-                    border: Border {
-                        color: border_color,
-                        width: 1.0,
-                        radius: 8.0.into(),
-                    },
-                    shadow: iced::Shadow {
-                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
-                        offset: iced::Vector::new(0.0, 4.0),
-                        blur_radius: 12.0,
-                    },
-                    ..Default::default()
-                });
+            let has_denials = !record.denied_capabilities.is_empty();
+            let failed = record.exit_code != 0;
+            let border_color = if has_denials {
+                Some(Color::from_rgb(0.9, 0.5, 0.2))
+            } else if failed {
+                Some(Color::from_rgb(0.6, 0.25, 0.25))
+            } else {
+                None
+            };
 
-                col.push(card)
+            let card = container(
+                column![header, details, usage_section, denied_section]
+                    .spacing(12)
+                    .padding(20),
+            )
+            .width(Length::Fill)
+            .style(move |theme| container::Style {
+                background: Some(iced::Background::Color(theme::palette(theme).surface)),
+                border: Border {
+                    color: border_color.unwrap_or(theme::palette(theme).border),
+                    width: 1.0,
+                    radius: 8.0.into(),
+                },
+                shadow: iced::Shadow {
+                    color: theme::palette(theme).shadow,
+                    offset: iced::Vector::new(0.0, 4.0),
+                    blur_radius: 12.0,
+                },
+                ..Default::default()
             });
 
-    let empty_state = if records.is_empty() {
+            col.push(mouse_area(card).on_press(Message::ShowSandboxDetails(record.id.clone())))
+        });
+
+    let empty_state = if daemon_status == DaemonStatus::Offline {
+        column![
+            text("Daemon offline — cannot load history")
+                .size(16)
+                .color(Color::from_rgb(1.0, 0.7, 0.0)),
+            text(if records.is_empty() {
+                "Start hopsd or check the connection in Settings, then reconnect."
+            } else {
+                "Showing the last loaded runs, which may be out of date."
+            })
+            .size(12)
+            .style(theme::muted_text),
+            text(connection_error.unwrap_or_default())
+                .size(12)
+                .color(Color::from_rgb(0.9, 0.35, 0.35)),
+            button(text("↻ Reconnect").size(14))
+                .on_press(Message::ReconnectDaemon)
+                .padding([10, 16])
+                .style(|_theme, status| {
+                    let base_color = Color::from_rgb(0.2, 0.5, 0.8);
+                    let hover_color = Color::from_rgb(0.25, 0.55, 0.85);
+                    button::Style {
+                        background: Some(iced::Background::Color(
+                            if matches!(status, button::Status::Hovered) {
+                                hover_color
+                            } else {
+                                base_color
+                            },
+                        )),
+                        text_color: Color::WHITE,
+                        border: Border {
+                            color: Color::from_rgb(0.3, 0.6, 0.9),
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                }),
+        ]
+        .spacing(10)
+    } else if daemon_status == DaemonStatus::Unknown && records.is_empty() {
+        column![text("Connecting to the daemon…")
+            .size(16)
+            .style(theme::muted_text)]
+    } else if loading && records.is_empty() {
+        column![container(
+            text("⏳ Loading history…")
+                .size(16)
+                .style(theme::muted_text)
+        )
+        .center_x(Length::Fill)
+        .padding(40)]
+    } else if records.is_empty() {
         column![text("No sandbox runs recorded yet.")
             .size(16)
             .color(Color::from_rgb(0.6, 0.6, 0.6))]
@@ -168,13 +539,17 @@ pub fn view<'a>(records: &'a [RunRecord], filter: &'a str) -> Element<'a, Messag
                 container(
                     text(format!("📊 Total: {}", total))
                         .size(14)
-                        .color(Color::from_rgb(0.9, 0.9, 0.95))
+                        .style(|theme| text::Style {
+                            color: Some(theme::palette(theme).text),
+                        })
                 )
                 .padding([6, 12])
-                .style(|_theme| container::Style {
-                    background: Some(iced::Background::Color(Color::from_rgb(0.25, 0.25, 0.3))),
+                .style(|theme| container::Style {
+                    background: Some(iced::Background::Color(
+                        theme::palette(theme).surface_raised
+                    )),
                     border: Border {
-                        color: Color::from_rgb(0.4, 0.4, 0.45),
+                        color: theme::palette(theme).border,
                         width: 1.0,
                         radius: 6.0.into(),
                     },
@@ -233,12 +608,59 @@ pub fn view<'a>(records: &'a [RunRecord], filter: &'a str) -> Element<'a, Messag
         container(row![])
     };
 
+    let page_button = |label: &'a str, target: Option<usize>| {
+        button(text(label).size(14))
+            .on_press_maybe(target.map(Message::HistoryPageChanged))
+            .padding([6, 12])
+            .style(|theme, status| {
+                let palette = theme::palette(theme);
+                button::Style {
+                    background: Some(iced::Background::Color(match status {
+                        button::Status::Hovered => palette.nav_inactive_hover,
+                        _ => palette.nav_inactive,
+                    })),
+                    text_color: if matches!(status, button::Status::Disabled) {
+                        palette.neutral
+                    } else {
+                        palette.text
+                    },
+                    border: Border {
+                        color: palette.nav_border,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    ..Default::default()
+                }
+            })
+    };
+
+    let pagination = if page_count > 1 {
+        row![
+            page_button("← Prev", page.checked_sub(1)),
+            text(format!(
+                "Page {} of {} ({} records)",
+                page + 1,
+                page_count,
+                filtered_records.len()
+            ))
+            .size(12)
+            .style(theme::muted_text),
+            page_button("Next →", Some(page + 1).filter(|&next| next < page_count)),
+        ]
+        .spacing(15)
+        .align_y(iced::alignment::Vertical::Center)
+    } else {
+        row![]
+    };
+
     let content = column![
         title,
         filter_input,
+        date_inputs,
         summary,
         empty_state,
-        scrollable(history_list),
+        scrollable(history_list).height(Length::Fill),
+        pagination,
     ]
     .spacing(20)
     .padding(30);
@@ -248,3 +670,457 @@ pub fn view<'a>(records: &'a [RunRecord], filter: &'a str) -> Element<'a, Messag
         .height(Length::Fill)
         .into()
 }
+
+pub fn sandbox_details(details: &SandboxDetails) -> Element<'_, Message> {
+    let body: Element<'_, Message> = match &details.status {
+        None => text("Loading status...").style(theme::muted_text).into(),
+        Some(Err(error)) => text(error)
+            .size(14)
+            .color(Color::from_rgb(0.9, 0.4, 0.4))
+            .into(),
+        Some(Ok(status)) => {
+            let state = match status.state() {
+                SandboxState::Unknown => "Unknown",
+                SandboxState::Starting => "Starting",
+                SandboxState::Running => "Running",
+                SandboxState::Stopped => "Stopped",
+                SandboxState::Failed => "Failed",
+            };
+            let exit_code = status
+                .exit_code
+                .map(|code| code.to_string())
+                .unwrap_or_else(|| "—".to_string());
+            let command = if status.command.is_empty() {
+                "—".to_string()
+            } else {
+                status.command.join(" ")
+            };
+            let usage = status.resource_usage.unwrap_or_default();
+
+            column![
+                detail_row("Status", state.to_string()),
+                detail_row("PID", status.pid.to_string()),
+                detail_row("Exit code", exit_code),
+                detail_row("Command", command),
+                detail_row("CPU", format!("{:.1}%", usage.cpu_percent)),
+                detail_row(
+                    "Memory",
+                    format!("{:.1} MB", usage.memory_bytes as f64 / (1024.0 * 1024.0))
+                ),
+                detail_row("Processes", usage.process_count.to_string()),
+            ]
+            .spacing(8)
+            .into()
+        }
+    };
+
+    let close_btn = button(
+        text("Close")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::CloseSandboxDetails)
+    .width(Length::Fill)
+    .padding(10)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    palette.neutral_hover
+                } else {
+                    palette.neutral
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    column![
+        text("SANDBOX DETAILS").size(20),
+        text(&details.sandbox_id).size(12).style(theme::muted_text),
+        body,
+        close_btn,
+    ]
+    .spacing(15)
+    .into()
+}
+
+pub fn clear_confirmation<'a>(finished_count: usize) -> Element<'a, Message> {
+    let cancel_btn = button(
+        text("Cancel")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::CancelClearHistory)
+    .width(Length::Fill)
+    .padding(10)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    palette.neutral_hover
+                } else {
+                    palette.neutral
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let clear_btn = button(
+        text("🗑 Clear")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::ClearHistory)
+    .width(Length::Fill)
+    .padding(10)
+    .style(|_theme, status| button::Style {
+        background: Some(iced::Background::Color(
+            if matches!(status, button::Status::Hovered) {
+                Color::from_rgb(0.85, 0.25, 0.25)
+            } else {
+                Color::from_rgb(0.8, 0.2, 0.2)
+            },
+        )),
+        text_color: Color::WHITE,
+        border: Border {
+            color: Color::from_rgb(0.9, 0.3, 0.3),
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        ..Default::default()
+    });
+
+    column![
+        text("CLEAR HISTORY").size(20),
+        text(format!(
+            "Remove {} finished run{} from the history? Running sandboxes are kept.",
+            finished_count,
+            if finished_count == 1 { "" } else { "s" }
+        ))
+        .size(14)
+        .style(theme::muted_text),
+        row![cancel_btn, clear_btn].spacing(10),
+    ]
+    .spacing(20)
+    .into()
+}
+
+pub fn stop_all_confirmation<'a>(running_count: usize) -> Element<'a, Message> {
+    let cancel_btn = button(
+        text("Cancel")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::CancelStopAll)
+    .width(Length::Fill)
+    .padding(10)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    palette.neutral_hover
+                } else {
+                    palette.neutral
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let stop_btn = button(
+        text("⏹ Stop all")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::StopAll)
+    .width(Length::Fill)
+    .padding(10)
+    .style(|_theme, status| button::Style {
+        background: Some(iced::Background::Color(
+            if matches!(status, button::Status::Hovered) {
+                Color::from_rgb(0.85, 0.25, 0.25)
+            } else {
+                Color::from_rgb(0.8, 0.2, 0.2)
+            },
+        )),
+        text_color: Color::WHITE,
+        border: Border {
+            color: Color::from_rgb(0.9, 0.3, 0.3),
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        ..Default::default()
+    });
+
+    column![
+        text("STOP ALL SANDBOXES").size(20),
+        text(format!(
+            "Stop {} running sandbox{}?",
+            running_count,
+            if running_count == 1 { "" } else { "es" }
+        ))
+        .size(14)
+        .style(theme::muted_text),
+        row![cancel_btn, stop_btn].spacing(10),
+    ]
+    .spacing(20)
+    .into()
+}
+
+pub fn stop_confirmation(pending: &PendingStop) -> Element<'_, Message> {
+    let cancel_btn = button(
+        text("Cancel")
+            .width(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::CancelStopSandbox)
+    .width(Length::Fill)
+    .padding(10)
+    .style(|theme, status| {
+        let palette = theme::palette(theme);
+        button::Style {
+            background: Some(iced::Background::Color(
+                if matches!(status, button::Status::Hovered) {
+                    palette.neutral_hover
+                } else {
+                    palette.neutral
+                },
+            )),
+            text_color: Color::WHITE,
+            border: Border {
+                color: palette.neutral_border,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    });
+
+    let stop_btn = button(
+        text(if pending.force {
+            "⏹ Force stop"
+        } else {
+            "⏹ Stop"
+        })
+        .width(Length::Fill)
+        .align_x(iced::alignment::Horizontal::Center),
+    )
+    .on_press(Message::StopSandbox {
+        sandbox_id: pending.sandbox_id.clone(),
+        force: pending.force,
+    })
+    .width(Length::Fill)
+    .padding(10)
+    .style(|_theme, status| button::Style {
+        background: Some(iced::Background::Color(
+            if matches!(status, button::Status::Hovered) {
+                Color::from_rgb(0.85, 0.25, 0.25)
+            } else {
+                Color::from_rgb(0.8, 0.2, 0.2)
+            },
+        )),
+        text_color: Color::WHITE,
+        border: Border {
+            color: Color::from_rgb(0.9, 0.3, 0.3),
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        ..Default::default()
+    });
+
+    column![
+        text("STOP SANDBOX").size(20),
+        text(format!("Stop sandbox {}?", pending.sandbox_id))
+            .size(14)
+            .style(theme::muted_text),
+        checkbox(
+            "Force stop (kill without waiting for a clean exit)",
+            pending.force
+        )
+        .on_toggle(Message::StopForceToggled),
+        row![cancel_btn, stop_btn].spacing(10),
+    ]
+    .spacing(20)
+    .into()
+}
+
+fn sparkline(values: impl Iterator<Item = f64> + Clone) -> String {
+    let max = values.clone().fold(0.0, f64::max);
+    values
+        .map(|value| {
+            let level = if max > 0.0 {
+                ((value / max) * (SPARKLINE_LEVELS.len() - 1) as f64).round() as usize
+            } else {
+                0
+            };
+            SPARKLINE_LEVELS[level.min(SPARKLINE_LEVELS.len() - 1)]
+        })
+        .collect()
+}
+
+fn detail_row<'a>(label: &'a str, value: String) -> Element<'a, Message> {
+    row![
+        text(label)
+            .size(13)
+            .style(theme::muted_text)
+            .width(Length::Fixed(100.0)),
+        text(value).size(13),
+    ]
+    .spacing(10)
+    .into()
+}
+
+fn highlighted<'a>(
+    prefix: Option<&'a str>,
+    value: &'a str,
+    query: &str,
+) -> text::Rich<'a, Message> {
+    let mut spans = Vec::new();
+    if let Some(prefix) = prefix {
+        spans.push(span(prefix));
+    }
+
+    let lowered = value.to_lowercase();
+    let found = if query.is_empty() || lowered.len() != value.len() {
+        None
+    } else {
+        lowered.find(query)
+    };
+
+    match found {
+        Some(start) => {
+            let end = start + query.len();
+            spans.push(span(&value[..start]));
+            spans.push(
+                span(&value[start..end])
+                    .color(Color::from_rgb(0.1, 0.1, 0.1))
+                    .background(Color::from_rgb(1.0, 0.85, 0.3))
+                    .border(Border {
+                        radius: 2.0.into(),
+                        ..Default::default()
+                    }),
+            );
+            spans.push(span(&value[end..]));
+        }
+        None => spans.push(span(value)),
+    }
+
+    rich_text(spans)
+}
+
+fn normalize_query(query: &str) -> String {
+    query.trim().to_lowercase()
+}
+
+fn record_matches(record: &RunRecord, query: &str) -> bool {
+    let status = if record.exit_code == 0 {
+        "success"
+    } else {
+        "failed"
+    };
+    record.id.to_lowercase().contains(query)
+        || record.profile_name.to_lowercase().contains(query)
+        || record.exit_code.to_string().contains(query)
+        || status.contains(query)
+        || record
+            .denied_capabilities
+            .iter()
+            .any(|denied| denied.to_lowercase().contains(query))
+}
+
+fn parse_date_bound(input: &str) -> Result<Option<i64>, ()> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    let mut parts = input.splitn(3, '-');
+    let mut next_part = || parts.next().and_then(|part| part.parse::<i64>().ok());
+    let (Some(year), Some(month), Some(day)) = (next_part(), next_part(), next_part()) else {
+        return Err(());
+    };
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(());
+    }
+
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days_since_epoch = era * 146_097 + day_of_era - 719_468;
+
+    Ok(Some(days_since_epoch * SECONDS_PER_DAY))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: &str, profile_name: &str) -> RunRecord {
+        RunRecord {
+            id: id.to_string(),
+            profile_name: profile_name.to_string(),
+            command: String::new(),
+            start_time: String::new(),
+            started_at: 0,
+            duration: String::new(),
+            exit_code: 0,
+            pid: 0,
+            state: SandboxState::Stopped,
+            denied_capabilities: vec![],
+        }
+    }
+
+    #[test]
+    fn mixed_case_query_matches_profile_and_id() {
+        let record = record("Sandbox-ABC123", "Web-Server");
+
+        assert!(record_matches(&record, &normalize_query("wEB-sERVER")));
+        assert!(record_matches(&record, &normalize_query("  sandbox-abc ")));
+        assert!(record_matches(&record, &normalize_query("SUCCESS")));
+        assert!(!record_matches(&record, &normalize_query("Database")));
+    }
+
+    #[test]
+    fn query_matches_denied_capabilities() {
+        let mut record = record("sandbox-1", "web");
+        record.denied_capabilities = vec!["mount:/etc/ssh".to_string()];
+
+        assert!(record_matches(&record, &normalize_query("/ETC/SSH")));
+        assert!(record_matches(&record, &normalize_query("mount:")));
+        assert!(!record_matches(&record, &normalize_query("write:")));
+    }
+
+    #[test]
+    fn date_bounds_parse_to_utc_midnight() {
+        assert_eq!(parse_date_bound(""), Ok(None));
+        assert_eq!(parse_date_bound("1970-01-02"), Ok(Some(SECONDS_PER_DAY)));
+        assert_eq!(parse_date_bound("2026-10-15"), Ok(Some(1_792_022_400)));
+        assert_eq!(parse_date_bound("2026-13-01"), Err(()));
+    }
+}
@@ -0,0 +1,222 @@
+use crate::app::{ConnectionInputs, DaemonStatus, Message};
+use crate::grpc_client::GrpcClient;
+use crate::theme::{self, AppTheme};
+use crate::utils::settings::{ConnectionMode, Settings, DEFAULT_HISTORY_REFRESH_SECS};
+use iced::widget::{
+    button, checkbox, column, container, pick_list, row, scrollable, text, text_input,
+};
+use iced::{Border, Color, Element, Length};
+
+pub fn view<'a>(
+    settings: &'a Settings,
+    connection_inputs: &'a ConnectionInputs,
+    daemon_status: DaemonStatus,
+    connection_error: Option<&'a str>,
+) -> Element<'a, Message> {
+    let title = text("SETTINGS").size(32);
+
+    let default_socket_path = GrpcClient::default_socket_path()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| "~/.hops/hops.sock".to_string());
+
+    let status_line = match daemon_status {
+        DaemonStatus::Connected => text("● Connected to daemon")
+            .size(12)
+            .color(Color::from_rgb(0.0, 0.8, 0.0)),
+        DaemonStatus::Offline => text("● Not connected")
+            .size(12)
+            .color(Color::from_rgb(0.8, 0.0, 0.0)),
+        DaemonStatus::Unknown => text("● Connecting...")
+            .size(12)
+            .color(Color::from_rgb(0.6, 0.6, 0.0)),
+    };
+
+    let error_msg = if let Some(error) = connection_error {
+        container(
+            row![
+                text("⚠").size(14).color(Color::from_rgb(1.0, 0.7, 0.0)),
+                text(error).size(12).color(Color::from_rgb(1.0, 0.95, 0.95))
+            ]
+            .spacing(8)
+            .padding(8),
+        )
+        .style(|_theme| container::Style {
+            background: Some(iced::Background::Color(Color::from_rgb(0.6, 0.15, 0.15))),
+            border: Border {
+                color: Color::from_rgb(0.8, 0.3, 0.3),
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        })
+    } else {
+        container(text(""))
+    };
+
+    let reconnect_btn = button(text("↻ Save & Reconnect").size(14))
+        .on_press(Message::ApplyConnectionSettings)
+        .padding([10, 16])
+        .style(|_theme, status| {
+            let base_color = Color::from_rgb(0.2, 0.5, 0.8);
+            let hover_color = Color::from_rgb(0.25, 0.55, 0.85);
+            button::Style {
+                background: Some(iced::Background::Color(
+                    if matches!(status, button::Status::Hovered) {
+                        hover_color
+                    } else {
+                        base_color
+                    },
+                )),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: Color::from_rgb(0.3, 0.6, 0.9),
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }
+        });
+
+    let address_field = match connection_inputs.mode {
+        ConnectionMode::UnixSocket => column![
+            text("Socket Path").size(14),
+            text_input(&default_socket_path, &connection_inputs.socket_path)
+                .on_input(Message::SocketPathInputChanged)
+                .on_submit(Message::ApplyConnectionSettings)
+                .padding(10)
+                .width(Length::Fill),
+            text(format!(
+                "Leave empty to use the default socket ({})",
+                default_socket_path
+            ))
+            .size(12)
+            .style(theme::muted_text),
+        ],
+        ConnectionMode::Tcp => column![
+            text("Daemon Address").size(14),
+            text_input("http://127.0.0.1:50051", &connection_inputs.tcp_address)
+                .on_input(Message::TcpAddressInputChanged)
+                .on_submit(Message::ApplyConnectionSettings)
+                .padding(10)
+                .width(Length::Fill),
+            text("Plain-text gRPC over TCP, e.g. an SSH tunnel to a remote hopsd")
+                .size(12)
+                .style(theme::muted_text),
+        ],
+    }
+    .spacing(10);
+
+    let connection_section = column![
+        text("DAEMON CONNECTION").size(14),
+        pick_list(
+            ConnectionMode::ALL,
+            Some(connection_inputs.mode),
+            Message::ConnectionModeChanged
+        )
+        .padding(10)
+        .width(Length::Fill),
+        address_field,
+        row![reconnect_btn, status_line]
+            .spacing(15)
+            .align_y(iced::alignment::Vertical::Center),
+        error_msg,
+    ]
+    .spacing(10);
+
+    let appearance_section = column![
+        text("APPEARANCE").size(14),
+        pick_list(AppTheme::ALL, Some(settings.theme), Message::ThemeChanged)
+            .padding(10)
+            .width(Length::Fill),
+    ]
+    .spacing(10);
+
+    let refresh_secs = settings
+        .history_refresh_secs
+        .map(|secs| secs.to_string())
+        .unwrap_or_default();
+    let default_refresh = DEFAULT_HISTORY_REFRESH_SECS.to_string();
+
+    let history_section = column![
+        text("RUN HISTORY").size(14),
+        checkbox(
+            "Auto-refresh while viewing history",
+            settings.history_auto_refresh
+        )
+        .on_toggle(Message::HistoryAutoRefreshToggled),
+        row![
+            text("Refresh every").size(14),
+            text_input(&default_refresh, &refresh_secs)
+                .on_input(Message::HistoryRefreshIntervalChanged)
+                .padding(10)
+                .width(Length::Fixed(80.0)),
+            text("seconds").size(14),
+        ]
+        .spacing(10)
+        .align_y(iced::alignment::Vertical::Center),
+    ]
+    .spacing(10);
+
+    let run_section = column![
+        text("RUNNING SANDBOXES").size(14),
+        text_input(
+            "Default command, e.g. /bin/sh",
+            settings.default_run_command.as_deref().unwrap_or_default()
+        )
+        .on_input(Message::DefaultRunCommandChanged)
+        .padding(10)
+        .width(Length::Fill),
+        text("Prefilled in the run bar for profiles without a recent command")
+            .size(12)
+            .style(theme::muted_text),
+    ]
+    .spacing(10);
+
+    let export_schema_btn = button(text("⇪ Export policy schema").size(14))
+        .on_press(Message::ExportPolicySchema)
+        .padding([10, 16])
+        .style(|theme, status| {
+            let palette = theme::palette(theme);
+            button::Style {
+                background: Some(iced::Background::Color(
+                    if matches!(status, button::Status::Hovered) {
+                        palette.neutral_hover
+                    } else {
+                        palette.neutral
+                    },
+                )),
+                text_color: Color::WHITE,
+                border: Border {
+                    color: palette.neutral_border,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }
+        });
+
+    let developer_section = column![
+        text("DEVELOPER").size(14),
+        export_schema_btn,
+        text("JSON Schema for profile files, for validating TOML or JSON written outside Hops")
+            .size(12)
+            .style(theme::muted_text),
+    ]
+    .spacing(10);
+
+    let content = column![
+        title,
+        connection_section,
+        appearance_section,
+        history_section,
+        run_section,
+        developer_section
+    ]
+    .spacing(30)
+    .padding(30);
+
+    container(scrollable(content))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}